@@ -0,0 +1,170 @@
+//! Checksum algorithms commonly used to protect the integrity of a message, all computed over a byte slice.
+//!
+//! Each algorithm is available as a plain function, ex: [crc32()], as well as a unit struct implementing [Checksum] trait, ex: [Crc32],
+//! which is used by `#[byteserde(checksum = "crc32")]` attribute of the `byteserde_derive` crate.
+//! ```
+//! use byteserde::checksum::*;
+//! let msg = b"123456789";
+//! assert_eq!(crc32(msg), 0xCBF4_3926);
+//! assert_eq!(crc16_ccitt(msg), 0x29B1);
+//! assert_eq!(fletcher16(msg), 0x1EDE);
+//! assert_eq!(mod256(msg), 0xDD);
+//! assert_eq!(<Crc32 as Checksum>::checksum(msg), crc32(msg));
+//! ```
+
+/// Implemented by each supported checksum algorithm
+pub trait Checksum {
+    /// numeric type the checksum is carried as in the byte stream
+    type Value: Copy + PartialEq + Into<u64>;
+    /// name of the algorithm used in error messages, see [crate::error::ErrorKind::ChecksumMismatch]
+    const NAME: &'static str;
+    fn checksum(bytes: &[u8]) -> Self::Value;
+}
+
+/// CRC-32 (ISO-HDLC) as used by Ethernet, zip, png: reflected polynomial `0xEDB88320`, init `0xFFFFFFFF`, final xor `0xFFFFFFFF`
+pub struct Crc32;
+impl Checksum for Crc32 {
+    type Value = u32;
+    const NAME: &'static str = "crc32";
+    #[inline]
+    fn checksum(bytes: &[u8]) -> u32 {
+        crc32(bytes)
+    }
+}
+/// CRC-16/CCITT-FALSE: polynomial `0x1021`, init `0xFFFF`, no reflection, no final xor
+pub struct Crc16Ccitt;
+impl Checksum for Crc16Ccitt {
+    type Value = u16;
+    const NAME: &'static str = "crc16_ccitt";
+    #[inline]
+    fn checksum(bytes: &[u8]) -> u16 {
+        crc16_ccitt(bytes)
+    }
+}
+/// Fletcher-16, two running sums modulo 255 with the second sum in the high byte
+pub struct Fletcher16;
+impl Checksum for Fletcher16 {
+    type Value = u16;
+    const NAME: &'static str = "fletcher16";
+    #[inline]
+    fn checksum(bytes: &[u8]) -> u16 {
+        fletcher16(bytes)
+    }
+}
+/// Sum of all bytes modulo 256, as used by FIX protocol `10=` tag
+pub struct Mod256;
+impl Checksum for Mod256 {
+    type Value = u8;
+    const NAME: &'static str = "mod256";
+    #[inline]
+    fn checksum(bytes: &[u8]) -> u8 {
+        mod256(bytes)
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0_u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB8_8320,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+const CRC16_CCITT_TABLE: [u16; 256] = {
+    let mut table = [0_u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 0x8000 {
+                0x8000 => (crc << 1) ^ 0x1021,
+                _ => crc << 1,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for b in bytes {
+        crc = CRC32_TABLE[((crc ^ *b as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc ^ 0xFFFF_FFFF
+}
+
+pub fn crc16_ccitt(bytes: &[u8]) -> u16 {
+    let mut crc = 0xFFFF_u16;
+    for b in bytes {
+        crc = CRC16_CCITT_TABLE[((crc >> 8) ^ *b as u16) as usize] ^ (crc << 8);
+    }
+    crc
+}
+
+pub fn fletcher16(bytes: &[u8]) -> u16 {
+    let (mut sum1, mut sum2) = (0_u16, 0_u16);
+    for b in bytes {
+        sum1 = (sum1 + *b as u16) % 255;
+        sum2 = (sum2 + sum1) % 255;
+    }
+    (sum2 << 8) | sum1
+}
+
+pub fn mod256(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0_u8, |sum, b| sum.wrapping_add(*b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_check_values() {
+        setup::log::configure();
+        // https://reveng.sourceforge.io/crc-catalogue/ check values are computed over "123456789"
+        let msg = b"123456789";
+        info!("crc32: {:#010x}", crc32(msg));
+        assert_eq!(crc32(msg), 0xCBF4_3926);
+        info!("crc16_ccitt: {:#06x}", crc16_ccitt(msg));
+        assert_eq!(crc16_ccitt(msg), 0x29B1);
+
+        assert_eq!(fletcher16(b"abcde"), 0xC8F0);
+        assert_eq!(fletcher16(b"abcdef"), 0x2057);
+        assert_eq!(fletcher16(b"abcdefgh"), 0x0627);
+
+        // FIX 10= checksum of "8=FIX.4.2|9=5|35=0|" using SOH as a delimiter
+        let fix = b"8=FIX.4.2\x019=5\x0135=0\x01";
+        info!("mod256: {:03}", mod256(fix));
+        assert_eq!(mod256(fix) as u32, fix.iter().map(|b| *b as u32).sum::<u32>() % 256);
+
+        for f in [crc32, |b: &[u8]| crc16_ccitt(b) as u32, |b: &[u8]| fletcher16(b) as u32, |b: &[u8]| mod256(b) as u32] {
+            assert_ne!(f(b"123456789"), f(b"123456780"));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        setup::log::configure();
+        assert_eq!(crc32(&[]), 0);
+        assert_eq!(crc16_ccitt(&[]), 0xFFFF);
+        assert_eq!(fletcher16(&[]), 0);
+        assert_eq!(mod256(&[]), 0);
+    }
+}
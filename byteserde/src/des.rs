@@ -0,0 +1,168 @@
+use crate::{
+    prelude::{ByteDeserializerBytes, ByteDeserializerSlice},
+    utils::numerics::{be_bytes::FromBeBytes, le_bytes::FromLeBytes, ne_bytes::FromNeBytes, Endian},
+};
+
+/// Common interface of [ByteDeserializerSlice] and [ByteDeserializerBytes], which allows writing a single function that deserializes from either one.
+/// Used by `#[byteserde(with = module)]` attribute of the `byteserde_derive` crate where `module::deserialize` is invoked with both.
+/// ```
+/// use byteserde::prelude::*;
+/// fn deserialize<D: ByteDeserializer>(des: &mut D) -> byteserde::error::Result<u16> {
+///     des.deserialize_be()
+/// }
+/// let mut des = ByteDeserializerSlice::new(&[0x00, 0x01]);
+/// assert_eq!(deserialize(&mut des).unwrap(), 1);
+/// let mut des = ByteDeserializerBytes::new(bytes::Bytes::from_static(&[0x00, 0x01]));
+/// assert_eq!(deserialize(&mut des).unwrap(), 1);
+/// ```
+pub trait ByteDeserializer {
+    /// Tracks the bytes read and always set to the next unread byte in the buffer
+    fn idx(&self) -> usize;
+    /// Number of bytes remaining to be deserialized
+    fn remaining(&self) -> usize;
+    /// Protocol version, see [ByteDeserializerSlice::set_version()]
+    fn version(&self) -> Option<u32>;
+    fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+    fn deserialize_bytes_slice_remaining(&mut self) -> &[u8];
+    fn deserialize_bytes_slice(&mut self, len: usize) -> crate::error::Result<&[u8]>;
+    /// Consumes `len` bytes and returns a new deserializer bounded to just these bytes
+    fn deserialize_bounded(&mut self, len: usize) -> crate::error::Result<Self>
+    where Self: Sized;
+    fn peek_bytes_slice(&self, len: usize) -> crate::error::Result<&[u8]>;
+    /// Number of bytes preceding the first occurrence of `terminator` in the remaining bytes, does not consume any bytes
+    fn peek_until(&self, terminator: &[u8]) -> crate::error::Result<usize>;
+    fn deserialize_u8(&mut self) -> crate::error::Result<u8>;
+    fn deserialize_i8(&mut self) -> crate::error::Result<i8>;
+    fn deserialize_ne<const N: usize, T: FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T>;
+    fn deserialize_le<const N: usize, T: FromLeBytes<N, T>>(&mut self) -> crate::error::Result<T>;
+    fn deserialize_be<const N: usize, T: FromBeBytes<N, T>>(&mut self) -> crate::error::Result<T>;
+    /// Byte order, see [ByteDeserializerSlice::set_endian()]
+    fn endian(&self) -> Endian;
+    fn deserialize_endian<const N: usize, T: FromBeBytes<N, T> + FromLeBytes<N, T> + FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        match self.endian() {
+            Endian::Big => self.deserialize_be(),
+            Endian::Little => self.deserialize_le(),
+            Endian::Native => self.deserialize_ne(),
+        }
+    }
+}
+
+impl<'bytes> ByteDeserializer for ByteDeserializerSlice<'bytes> {
+    #[inline(always)]
+    fn endian(&self) -> Endian {
+        ByteDeserializerSlice::endian(self)
+    }
+    #[inline(always)]
+    fn version(&self) -> Option<u32> {
+        ByteDeserializerSlice::version(self)
+    }
+    #[inline(always)]
+    fn idx(&self) -> usize {
+        ByteDeserializerSlice::idx(self)
+    }
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        ByteDeserializerSlice::remaining(self)
+    }
+    #[inline(always)]
+    fn deserialize_bytes_slice_remaining(&mut self) -> &[u8] {
+        ByteDeserializerSlice::deserialize_bytes_slice_remaining(self)
+    }
+    #[inline(always)]
+    fn deserialize_bytes_slice(&mut self, len: usize) -> crate::error::Result<&[u8]> {
+        ByteDeserializerSlice::deserialize_bytes_slice(self, len)
+    }
+    #[inline(always)]
+    fn deserialize_bounded(&mut self, len: usize) -> crate::error::Result<Self> {
+        ByteDeserializerSlice::deserialize_bounded(self, len)
+    }
+    #[inline(always)]
+    fn peek_bytes_slice(&self, len: usize) -> crate::error::Result<&[u8]> {
+        ByteDeserializerSlice::peek_bytes_slice(self, len)
+    }
+    #[inline(always)]
+    fn peek_until(&self, terminator: &[u8]) -> crate::error::Result<usize> {
+        ByteDeserializerSlice::peek_until(self, terminator)
+    }
+    #[inline(always)]
+    fn deserialize_u8(&mut self) -> crate::error::Result<u8> {
+        ByteDeserializerSlice::deserialize_u8(self)
+    }
+    #[inline(always)]
+    fn deserialize_i8(&mut self) -> crate::error::Result<i8> {
+        ByteDeserializerSlice::deserialize_i8(self)
+    }
+    #[inline(always)]
+    fn deserialize_ne<const N: usize, T: FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerSlice::deserialize_ne(self)
+    }
+    #[inline(always)]
+    fn deserialize_le<const N: usize, T: FromLeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerSlice::deserialize_le(self)
+    }
+    #[inline(always)]
+    fn deserialize_be<const N: usize, T: FromBeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerSlice::deserialize_be(self)
+    }
+}
+
+impl ByteDeserializer for ByteDeserializerBytes {
+    #[inline(always)]
+    fn endian(&self) -> Endian {
+        ByteDeserializerBytes::endian(self)
+    }
+    #[inline(always)]
+    fn version(&self) -> Option<u32> {
+        ByteDeserializerBytes::version(self)
+    }
+    #[inline(always)]
+    fn idx(&self) -> usize {
+        ByteDeserializerBytes::idx(self)
+    }
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        ByteDeserializerBytes::remaining(self)
+    }
+    #[inline(always)]
+    fn deserialize_bytes_slice_remaining(&mut self) -> &[u8] {
+        ByteDeserializerBytes::deserialize_bytes_slice_remaining(self)
+    }
+    #[inline(always)]
+    fn deserialize_bytes_slice(&mut self, len: usize) -> crate::error::Result<&[u8]> {
+        ByteDeserializerBytes::deserialize_bytes_slice(self, len)
+    }
+    #[inline(always)]
+    fn deserialize_bounded(&mut self, len: usize) -> crate::error::Result<Self> {
+        ByteDeserializerBytes::deserialize_bounded(self, len)
+    }
+    #[inline(always)]
+    fn peek_bytes_slice(&self, len: usize) -> crate::error::Result<&[u8]> {
+        ByteDeserializerBytes::peek_bytes_slice(self, len)
+    }
+    #[inline(always)]
+    fn peek_until(&self, terminator: &[u8]) -> crate::error::Result<usize> {
+        ByteDeserializerBytes::peek_until(self, terminator)
+    }
+    #[inline(always)]
+    fn deserialize_u8(&mut self) -> crate::error::Result<u8> {
+        ByteDeserializerBytes::deserialize_u8(self)
+    }
+    #[inline(always)]
+    fn deserialize_i8(&mut self) -> crate::error::Result<i8> {
+        ByteDeserializerBytes::deserialize_i8(self)
+    }
+    #[inline(always)]
+    fn deserialize_ne<const N: usize, T: FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerBytes::deserialize_ne(self)
+    }
+    #[inline(always)]
+    fn deserialize_le<const N: usize, T: FromLeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerBytes::deserialize_le(self)
+    }
+    #[inline(always)]
+    fn deserialize_be<const N: usize, T: FromBeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        ByteDeserializerBytes::deserialize_be(self)
+    }
+}
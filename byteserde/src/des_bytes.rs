@@ -5,8 +5,9 @@ use bytes::Bytes;
 use crate::{
     error::SerDesError,
     utils::{
+        bytes::position_of,
         hex::{to_hex_line, to_hex_pretty},
-        numerics::{be_bytes::FromBeBytes, le_bytes::FromLeBytes, ne_bytes::FromNeBytes},
+        numerics::{be_bytes::FromBeBytes, le_bytes::FromLeBytes, ne_bytes::FromNeBytes, Endian},
     },
 };
 
@@ -32,6 +33,8 @@ use crate::{
 pub struct ByteDeserializerBytes {
     bytes: Bytes,
     idx: usize,
+    version: Option<u32>,
+    endian: Endian,
 }
 
 /// Provides a convenient way to view buffer content as both HEX and ASCII bytes where printable.
@@ -67,7 +70,39 @@ impl From<Vec<u8>> for ByteDeserializerBytes {
 
 impl ByteDeserializerBytes {
     pub fn new(bytes: Bytes) -> ByteDeserializerBytes {
-        ByteDeserializerBytes { bytes, idx: 0 }
+        ByteDeserializerBytes {
+            bytes,
+            idx: 0,
+            version: None,
+            endian: Endian::Native,
+        }
+    }
+    /// Protocol version used by members annotated with `#[byteserde(since = N)]` or `#[byteserde(until_version = N)]`, `None` means all of the members are deserialized.
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+    /// Sets protocol version, members whose version range does not include it are skipped during deserialization and set to their `Default`.
+    pub fn set_version(&mut self, version: u32) -> &mut Self {
+        self.version = Some(version);
+        self
+    }
+    /// Byte order used by [Self::deserialize_endian()], defaults to [Endian::Native]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+    /// Sets byte order used by [Self::deserialize_endian()] and members annotated with `#[byteserde(endian = "runtime")]`
+    pub fn set_endian(&mut self, endian: Endian) -> &mut Self {
+        self.endian = endian;
+        self
+    }
+    /// Calls `f` with `endian` set, restoring the current one afterwards, even if `f` fails. `#[derive(..)]` uses it for structs with explicit
+    /// `#[byteserde(endian = "be" | "le" | "ne")]` so that nested members using [Self::deserialize_endian()] inherit it
+    pub fn with_endian<R>(&mut self, endian: Endian, f: impl FnOnce(&mut Self) -> crate::error::Result<R>) -> crate::error::Result<R> {
+        let current = self.endian;
+        self.endian = endian;
+        let res = f(self);
+        self.endian = current;
+        res
     }
 
     /// Tracks the bytes read and always set to the next unread byte in the buffer. This is an inverse of [Self::remaining()]
@@ -86,6 +121,14 @@ impl ByteDeserializerBytes {
     pub fn is_empty(&self) -> bool {
         self.remaining() == 0
     }
+    /// Bytes already deserialized starting at `idx` and up to [Self::idx()], useful to verify a checksum over a consumed section
+    /// Returns an empty slice if `idx` is not behind [Self::idx()]
+    pub fn consumed_since(&self, idx: usize) -> &[u8] {
+        match idx < self.idx {
+            true => &self.bytes[idx..self.idx],
+            false => &[],
+        }
+    }
 
     #[cold]
     fn error(&self, n: usize) -> SerDesError {
@@ -94,9 +137,7 @@ impl ByteDeserializerBytes {
         // time:   [39.251 ns 39.333 ns 39.465 ns]
         // change: [-12.507% -11.603% -10.612%] (p = 0.00 < 0.05)
         // Performance has improved.
-        SerDesError {
-            message: format!("Failed to get a slice size: {n} bytes from {self:x}"),
-        }
+        SerDesError::new(format!("Failed to get a slice size: {n} bytes from {self:x}"))
     }
     /// consumes all of the remaining bytes in the buffer and returns them as slice
     pub fn deserialize_bytes_slice_remaining(&mut self) -> &[u8] {
@@ -119,22 +160,22 @@ impl ByteDeserializerBytes {
 
     #[inline(always)]
     pub fn deserialize_u8(&mut self) -> crate::error::Result<u8> {
-        let res = self.bytes.get(self.idx..);
+        let res = self.bytes.get(self.idx);
         match res {
             Some(v) => {
                 self.idx += 1;
-                Ok(v[0])
+                Ok(*v)
             }
             None => Err(self.error(1)),
         }
     }
     #[inline(always)]
     pub fn deserialize_i8(&mut self) -> crate::error::Result<i8> {
-        let res = self.bytes.get(self.idx..);
+        let res = self.bytes.get(self.idx);
         match res {
             Some(v) => {
                 self.idx += 1;
-                Ok(v[0] as i8)
+                Ok(*v as i8)
             }
             None => Err(self.error(1)),
         }
@@ -148,20 +189,36 @@ impl ByteDeserializerBytes {
         // TODO figure out why i can't call this method from deserialize_bytes_slice and just increment the index if success
         match self.bytes.get(self.idx..self.idx + len) {
             Some(v) => Ok(v),
-            None => Err(SerDesError {
-                message: format!(
-                    "ByteDeserializerBytes len: {len}, idx: {idx}, remaining: {rem}, requested: {req}, bytes:\n{self:#x}",
-                    len = self.len(),
-                    rem = &self.remaining(),
-                    req = len,
-                    idx = self.idx,
-                ),
-            }),
+            None => Err(SerDesError::new(format!(
+                "ByteDeserializerBytes len: {len}, idx: {idx}, remaining: {rem}, requested: {req}, bytes:\n{self:#x}",
+                len = self.len(),
+                rem = &self.remaining(),
+                req = len,
+                idx = self.idx,
+            ))),
         }
     }
+    /// number of bytes preceding the first occurrence of `terminator` in the remaining bytes, does not consume any bytes.
+    /// Fails if `terminator` is empty or not found
+    pub fn peek_until(&self, terminator: &[u8]) -> crate::error::Result<usize> {
+        match position_of(&self.bytes[self.idx..], terminator) {
+            Some(n) => Ok(n),
+            None => Err(SerDesError::new(format!("terminator: {terminator:02x?} not found, bytes:\n{self:#x}"))),
+        }
+    }
+    /// consumes `len` bytes from the buffer and returns a new [ByteDeserializerBytes] bounded to just these bytes, without copying them.
+    /// Fails if `len` is greater then [Self::remaining()]
+    pub fn deserialize_bounded(&mut self, len: usize) -> crate::error::Result<ByteDeserializerBytes> {
+        let bytes = self.peek_bytes(len)?;
+        self.advance_idx(len);
+        let mut des = ByteDeserializerBytes::new(bytes);
+        des.version = self.version;
+        des.endian = self.endian;
+        Ok(des)
+    }
     pub fn peek_bytes(&self, at: usize) -> crate::error::Result<Bytes> {
-        if self.remaining() > at {
-            Err(self.error(self.idx + at))
+        if at > self.remaining() {
+            Err(self.error(at))
         } else {
             Ok(self.bytes.clone().split_to(self.idx + at).split_off(self.idx))
         }
@@ -215,12 +272,26 @@ impl ByteDeserializerBytes {
         let r = self.deserialize_bytes_array_ref::<N>()?;
         Ok(T::from_bytes_ref(r))
     }
+    /// depletes `2` bytes for `u16`, etc. and returns after deserializing using endianess set by [Self::set_endian()]
+    #[inline]
+    pub fn deserialize_endian<const N: usize, T: FromBeBytes<N, T> + FromLeBytes<N, T> + FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        match self.endian {
+            Endian::Big => self.deserialize_be(),
+            Endian::Little => self.deserialize_le(),
+            Endian::Native => self.deserialize_ne(),
+        }
+    }
     /// creates a new instance of `T` type `struct`, depleting exactly the right amount of bytes from [ByteDeserializerBytes]
     /// `T` must implement [ByteDeserializeBytes] trait
     pub fn deserialize<T>(&mut self) -> crate::error::Result<T>
     where T: ByteDeserializeBytes<T> {
         T::byte_deserialize(self)
     }
+    /// same as [Self::deserialize()] but for types implementing [ByteDeserializeBytesWith] trait using context `ctx`
+    pub fn deserialize_with<C, T>(&mut self, ctx: &C) -> crate::error::Result<T>
+    where T: ByteDeserializeBytesWith<T, C> {
+        T::byte_deserialize_with(self, ctx)
+    }
 
     /// creates a new instance of T type struct, depleting `exactly` `len` bytes from [ByteDeserializerBytes].
     /// Intended for types with variable length such as Strings, Vec, etc.
@@ -228,6 +299,39 @@ impl ByteDeserializerBytes {
     where T: ByteDeserializeBytes<T> {
         T::byte_deserialize_take(self, len)
     }
+    /// same as [Self::deserialize_take()] but for types implementing [ByteDeserializeBytesWith] trait using context `ctx`
+    pub fn deserialize_take_with<C, T>(&mut self, len: usize, ctx: &C) -> crate::error::Result<T>
+    where T: ByteDeserializeBytesWith<T, C> {
+        T::byte_deserialize_take_with(self, len, ctx)
+    }
+}
+
+/// Same as [ByteDeserializeBytes] but with access to a user supplied context `C`, ex: a parent's header or negotiated session options.
+/// Implemented by `byteserde_derive` for structs annotated with `#[byteserde(ctx = C)]` and by every [ByteDeserializeBytes] type ignoring the context,
+/// hence any member can be nested in a struct with context.
+pub trait ByteDeserializeBytesWith<T, C> {
+    fn byte_deserialize_with(des: &mut ByteDeserializerBytes, ctx: &C) -> crate::error::Result<T>;
+
+    /// same as [ByteDeserializeBytes::byte_deserialize_take()] but using context `ctx`
+    fn byte_deserialize_take_with(des: &mut ByteDeserializerBytes, len: usize, ctx: &C) -> crate::error::Result<T> {
+        let bytes = des.peek_bytes(len)?;
+        let tmp_des = &mut ByteDeserializerBytes::new(bytes);
+        tmp_des.version = des.version;
+        tmp_des.endian = des.endian;
+        let v = Self::byte_deserialize_with(tmp_des, ctx)?;
+        des.advance_idx(len);
+        Ok(v)
+    }
+}
+impl<C, T: ByteDeserializeBytes<T>> ByteDeserializeBytesWith<T, C> for T {
+    #[inline]
+    fn byte_deserialize_with(des: &mut ByteDeserializerBytes, _ctx: &C) -> crate::error::Result<T> {
+        T::byte_deserialize(des)
+    }
+    #[inline]
+    fn byte_deserialize_take_with(des: &mut ByteDeserializerBytes, len: usize, _ctx: &C) -> crate::error::Result<T> {
+        T::byte_deserialize_take(des, len)
+    }
 }
 
 /// This trait is to be implemented by any struct, example `MyFavStruct`, to be compatible with [`ByteDeserializerBytes::deserialize<MyFavStruct>()`]
@@ -242,6 +346,8 @@ pub trait ByteDeserializeBytes<T> {
     fn byte_deserialize_take(des: &mut ByteDeserializerBytes, len: usize) -> crate::error::Result<T> {
         let bytes = des.peek_bytes(len)?;
         let tmp_des = &mut ByteDeserializerBytes::new(bytes);
+        tmp_des.version = des.version;
+        tmp_des.endian = des.endian;
         let result = Self::byte_deserialize(tmp_des);
         match result {
             Ok(v) => {
@@ -252,6 +358,21 @@ pub trait ByteDeserializeBytes<T> {
         }
         // Err(SerDesError{message: "Not implemented yet".to_string()})
     }
+
+    /// Greedily deserializes the remaining bytes into `Vec<T>`, used by the `Vec<T>` impl. `u8` overrides it to copy the remaining bytes at once.
+    /// Returns [SerDesError](crate::error::SerDesError) if an element does not deplete any bytes, which would otherwise never terminate.
+    #[inline]
+    fn byte_deserialize_remaining(des: &mut ByteDeserializerBytes) -> crate::error::Result<Vec<T>> {
+        let mut elements = Vec::new();
+        while !des.is_empty() {
+            let remaining = des.remaining();
+            elements.push(Self::byte_deserialize(des)?);
+            if remaining == des.remaining() {
+                return Err(crate::utils::std_types::error_no_progress::<T>());
+            }
+        }
+        Ok(elements)
+    }
 }
 
 /// Greedy deserialization of the remaining byte stream into a `Vec<u8>`
@@ -269,3 +390,9 @@ where T: ByteDeserializeBytes<T> {
     let de = &mut ByteDeserializerBytes::new(bytes);
     T::byte_deserialize(de)
 }
+/// Analogous to [from_bytes] but for types implementing [ByteDeserializeBytesWith] using context `ctx`
+pub fn from_bytes_with<C, T>(bytes: Bytes, ctx: &C) -> crate::error::Result<T>
+where T: ByteDeserializeBytesWith<T, C> {
+    let de = &mut ByteDeserializerBytes::new(bytes);
+    T::byte_deserialize_with(de, ctx)
+}
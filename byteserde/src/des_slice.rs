@@ -4,8 +4,9 @@ use crate::{
     error::SerDesError,
     prelude::ByteSerializerHeap,
     utils::{
+        bytes::position_of,
         hex::{to_hex_line, to_hex_pretty},
-        numerics::{be_bytes::FromBeBytes, le_bytes::FromLeBytes, ne_bytes::FromNeBytes},
+        numerics::{be_bytes::FromBeBytes, le_bytes::FromLeBytes, ne_bytes::FromNeBytes, Endian},
     },
 };
 
@@ -33,6 +34,8 @@ use super::ser_stack::ByteSerializerStack;
 pub struct ByteDeserializerSlice<'slice> {
     bytes: &'slice [u8],
     idx: usize,
+    version: Option<u32>,
+    endian: Endian,
 }
 
 /// Provides a convenient way to view buffer content as both HEX and ASCII bytes where printable.
@@ -58,8 +61,40 @@ impl<'bytes> LowerHex for ByteDeserializerSlice<'bytes> {
 
 impl<'bytes> ByteDeserializerSlice<'bytes> {
     #[inline(always)]
-    pub fn new(bytes: &[u8]) -> ByteDeserializerSlice {
-        ByteDeserializerSlice { bytes, idx: 0 }
+    pub fn new(bytes: &[u8]) -> ByteDeserializerSlice<'_> {
+        ByteDeserializerSlice {
+            bytes,
+            idx: 0,
+            version: None,
+            endian: Endian::Native,
+        }
+    }
+    /// Protocol version used by members annotated with `#[byteserde(since = N)]` or `#[byteserde(until_version = N)]`, `None` means all of the members are deserialized.
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+    /// Sets protocol version, members whose version range does not include it are skipped during deserialization and set to their `Default`.
+    pub fn set_version(&mut self, version: u32) -> &mut Self {
+        self.version = Some(version);
+        self
+    }
+    /// Byte order used by [Self::deserialize_endian()], defaults to [Endian::Native]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+    /// Sets byte order used by [Self::deserialize_endian()] and members annotated with `#[byteserde(endian = "runtime")]`
+    pub fn set_endian(&mut self, endian: Endian) -> &mut Self {
+        self.endian = endian;
+        self
+    }
+    /// Calls `f` with `endian` set, restoring the current one afterwards, even if `f` fails. `#[derive(..)]` uses it for structs with explicit
+    /// `#[byteserde(endian = "be" | "le" | "ne")]` so that nested members using [Self::deserialize_endian()] inherit it
+    pub fn with_endian<R>(&mut self, endian: Endian, f: impl FnOnce(&mut Self) -> crate::error::Result<R>) -> crate::error::Result<R> {
+        let current = self.endian;
+        self.endian = endian;
+        let res = f(self);
+        self.endian = current;
+        res
     }
 
     pub fn reset(&mut self) {
@@ -82,6 +117,14 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
     pub fn is_empty(&self) -> bool {
         self.remaining() == 0
     }
+    /// Bytes already deserialized starting at `idx` and up to [Self::idx()], useful to verify a checksum over a consumed section
+    /// Returns an empty slice if `idx` is not behind [Self::idx()]
+    pub fn consumed_since(&self, idx: usize) -> &'bytes [u8] {
+        match idx < self.idx {
+            true => &self.bytes[idx..self.idx],
+            false => &[],
+        }
+    }
 
     #[cold]
     fn error(&self, n: usize) -> SerDesError {
@@ -90,9 +133,7 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
         // time:   [39.251 ns 39.333 ns 39.465 ns]
         // change: [-12.507% -11.603% -10.612%] (p = 0.00 < 0.05)
         // Performance has improved.
-        SerDesError {
-            message: format!("Failed to get a slice size: {n} bytes from {self:x}"),
-        }
+        SerDesError::new(format!("Failed to get a slice size: {n} bytes from {self:x}"))
     }
     /// consumes all of the remaining bytes in the buffer and returns them as slice
     pub fn deserialize_bytes_slice_remaining(&mut self) -> &'bytes [u8] {
@@ -110,22 +151,22 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
 
     #[inline(always)]
     pub fn deserialize_u8(&mut self) -> crate::error::Result<u8> {
-        let res = self.bytes.get(self.idx..);
+        let res = self.bytes.get(self.idx);
         match res {
             Some(v) => {
                 self.idx += 1;
-                Ok(v[0])
+                Ok(*v)
             }
             None => Err(self.error(1)),
         }
     }
     #[inline(always)]
     pub fn deserialize_i8(&mut self) -> crate::error::Result<i8> {
-        let res = self.bytes.get(self.idx..);
+        let res = self.bytes.get(self.idx);
         match res {
             Some(v) => {
                 self.idx += 1;
-                Ok(v[0] as i8)
+                Ok(*v as i8)
             }
             None => Err(self.error(1)),
         }
@@ -140,12 +181,27 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
     pub fn peek_bytes_slice(&self, len: usize) -> crate::error::Result<&'bytes [u8]> {
         match self.bytes.get(self.idx..self.idx + len) {
             Some(v) => Ok(v),
-            None => Err(SerDesError {
-                message: format!("requested: {req}, bytes:\n{self:#x}", req = len,),
-            }),
+            None => Err(SerDesError::new(format!("requested: {req}, bytes:\n{self:#x}", req = len,))),
         }
     }
 
+    /// number of bytes preceding the first occurrence of `terminator` in the remaining bytes, does not consume any bytes.
+    /// Fails if `terminator` is empty or not found
+    pub fn peek_until(&self, terminator: &[u8]) -> crate::error::Result<usize> {
+        match position_of(&self.bytes[self.idx..], terminator) {
+            Some(n) => Ok(n),
+            None => Err(SerDesError::new(format!("terminator: {terminator:02x?} not found, bytes:\n{self:#x}"))),
+        }
+    }
+    /// consumes `len` bytes from the buffer and returns a new [ByteDeserializerSlice] bounded to just these bytes.
+    /// Fails if `len` is greater then [Self::remaining()]
+    pub fn deserialize_bounded(&mut self, len: usize) -> crate::error::Result<ByteDeserializerSlice<'bytes>> {
+        let version = self.version;
+        let mut des = ByteDeserializerSlice::new(self.deserialize_bytes_slice(len)?);
+        des.version = version;
+        des.endian = self.endian;
+        Ok(des)
+    }
     #[inline]
     pub fn deserialize_bytes_array_ref<const N: usize>(&mut self) -> crate::error::Result<&'bytes [u8; N]> {
         match self.bytes.get(self.idx..self.idx + N) {
@@ -195,12 +251,26 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
         let r = self.deserialize_bytes_array_ref::<N>()?;
         Ok(T::from_bytes_ref(r))
     }
+    /// depletes `2` bytes for `u16`, etc. and returns after deserializing using endianess set by [Self::set_endian()]
+    #[inline]
+    pub fn deserialize_endian<const N: usize, T: FromBeBytes<N, T> + FromLeBytes<N, T> + FromNeBytes<N, T>>(&mut self) -> crate::error::Result<T> {
+        match self.endian {
+            Endian::Big => self.deserialize_be(),
+            Endian::Little => self.deserialize_le(),
+            Endian::Native => self.deserialize_ne(),
+        }
+    }
     /// creates a new instance of `T` type `struct`, depleting exactly the right amount of bytes from [ByteDeserializerSlice]
     /// `T` must implement [ByteDeserializeSlice] trait
     pub fn deserialize<T>(&mut self) -> crate::error::Result<T>
     where T: ByteDeserializeSlice<T> {
         T::byte_deserialize(self)
     }
+    /// same as [Self::deserialize()] but for types implementing [ByteDeserializeSliceWith] trait using context `ctx`
+    pub fn deserialize_with<C, T>(&mut self, ctx: &C) -> crate::error::Result<T>
+    where T: ByteDeserializeSliceWith<T, C> {
+        T::byte_deserialize_with(self, ctx)
+    }
 
     /// creates a new instance of T type struct, depleting `exactly` `len` bytes from [ByteDeserializerSlice].
     /// Intended for types with variable length such as Strings, Vec, etc.
@@ -208,6 +278,39 @@ impl<'bytes> ByteDeserializerSlice<'bytes> {
     where T: ByteDeserializeSlice<T> {
         T::byte_deserialize_take(self, len)
     }
+    /// same as [Self::deserialize_take()] but for types implementing [ByteDeserializeSliceWith] trait using context `ctx`
+    pub fn deserialize_take_with<C, T>(&mut self, len: usize, ctx: &C) -> crate::error::Result<T>
+    where T: ByteDeserializeSliceWith<T, C> {
+        T::byte_deserialize_take_with(self, len, ctx)
+    }
+}
+
+/// Same as [ByteDeserializeSlice] but with access to a user supplied context `C`, ex: a parent's header or negotiated session options.
+/// Implemented by `byteserde_derive` for structs annotated with `#[byteserde(ctx = C)]` and by every [ByteDeserializeSlice] type ignoring the context,
+/// hence any member can be nested in a struct with context.
+pub trait ByteDeserializeSliceWith<T, C> {
+    fn byte_deserialize_with(des: &mut ByteDeserializerSlice, ctx: &C) -> crate::error::Result<T>;
+
+    /// same as [ByteDeserializeSlice::byte_deserialize_take()] but using context `ctx`
+    fn byte_deserialize_take_with(des: &mut ByteDeserializerSlice, len: usize, ctx: &C) -> crate::error::Result<T> {
+        let bytes = des.peek_bytes_slice(len)?;
+        let tmp_des = &mut ByteDeserializerSlice::new(bytes);
+        tmp_des.version = des.version;
+        tmp_des.endian = des.endian;
+        let v = Self::byte_deserialize_with(tmp_des, ctx)?;
+        des.advance_idx(bytes.len());
+        Ok(v)
+    }
+}
+impl<C, T: ByteDeserializeSlice<T>> ByteDeserializeSliceWith<T, C> for T {
+    #[inline]
+    fn byte_deserialize_with(des: &mut ByteDeserializerSlice, _ctx: &C) -> crate::error::Result<T> {
+        T::byte_deserialize(des)
+    }
+    #[inline]
+    fn byte_deserialize_take_with(des: &mut ByteDeserializerSlice, len: usize, _ctx: &C) -> crate::error::Result<T> {
+        T::byte_deserialize_take(des, len)
+    }
 }
 
 /// This trait is to be implemented by any struct, example `MyFavStruct`, to be compatible with [`ByteDeserializerSlice::deserialize<MyFavStruct>()`]
@@ -223,6 +326,8 @@ pub trait ByteDeserializeSlice<T> {
     fn byte_deserialize_take(des: &mut ByteDeserializerSlice, len: usize) -> crate::error::Result<T> {
         let bytes = des.peek_bytes_slice(len)?;
         let tmp_des = &mut ByteDeserializerSlice::new(bytes);
+        tmp_des.version = des.version;
+        tmp_des.endian = des.endian;
         let result = Self::byte_deserialize(tmp_des);
         match result {
             Ok(v) => {
@@ -232,12 +337,20 @@ pub trait ByteDeserializeSlice<T> {
             Err(e) => Err(e),
         }
     }
-}
 
-/// Greedy deserialization of the remaining byte stream into a `Vec<u8>`
-impl ByteDeserializeSlice<Vec<u8>> for Vec<u8> {
-    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<Vec<u8>> {
-        Ok(des.deserialize_bytes_slice_remaining().into())
+    /// Greedily deserializes the remaining bytes into `Vec<T>`, used by the `Vec<T>` impl. `u8` overrides it to copy the remaining bytes at once.
+    /// Returns [SerDesError](crate::error::SerDesError) if an element does not deplete any bytes, which would otherwise never terminate.
+    #[inline]
+    fn byte_deserialize_remaining(des: &mut ByteDeserializerSlice) -> crate::error::Result<Vec<T>> {
+        let mut elements = Vec::new();
+        while !des.is_empty() {
+            let remaining = des.remaining();
+            elements.push(Self::byte_deserialize(des)?);
+            if remaining == des.remaining() {
+                return Err(crate::utils::std_types::error_no_progress::<T>());
+            }
+        }
+        Ok(elements)
     }
 }
 
@@ -249,6 +362,13 @@ where T: ByteDeserializeSlice<T> {
     T::byte_deserialize(de)
 }
 
+/// Analogous to [from_slice] but for types implementing [ByteDeserializeSliceWith] using context `ctx`
+pub fn from_slice_with<C, T>(bytes: &[u8], ctx: &C) -> crate::error::Result<T>
+where T: ByteDeserializeSliceWith<T, C> {
+    let de = &mut ByteDeserializerSlice::new(bytes);
+    T::byte_deserialize_with(de, ctx)
+}
+
 /// This is a short cut method that uses [`ByteSerializerStack<CAP>::as_slice()`] method to issue a [from_slice] call.
 pub fn from_serializer_stack<const CAP: usize, T>(ser: &ByteSerializerStack<CAP>) -> crate::error::Result<T>
 where T: ByteDeserializeSlice<T> {
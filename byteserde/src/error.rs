@@ -1,20 +1,54 @@
 use std::fmt::{self, Display};
 
+#[non_exhaustive]
 pub struct SerDesError {
     pub message: String,
+    kind: ErrorKind,
+}
+impl SerDesError {
+    /// Error of [ErrorKind::Other] kind
+    pub fn new(message: impl Into<String>) -> Self {
+        Self::with_kind(ErrorKind::Other, message)
+    }
+    /// Error of a specific [ErrorKind] so that the caller can match on it
+    pub fn with_kind(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self { message: message.into(), kind }
+    }
+    /// Classifies the failure, see [ErrorKind]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+/// Classifies a [SerDesError] so that the caller can react to specific failures without parsing the `message`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Any failure which does not have a dedicated kind, ex: not enough bytes to serialize or deserialize
+    Other,
+    /// Value in the byte stream does not match the one required by the protocol, ex: a magic number or a version byte
+    UnexpectedValue,
+    /// Value failed `#[byteserde(validate = ..)]` hook
+    ValidationFailed,
+    /// Checksum carried in the byte stream does not match the one computed over the received bytes, see [crate::checksum]
+    ChecksumMismatch {
+        algorithm: &'static str,
+        expected: u64,
+        actual: u64,
+    },
 }
 
 impl std::error::Error for SerDesError {}
 
 impl fmt::Debug for SerDesError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("SerDesError").field("message", &self.message).finish()
+        f.debug_struct("SerDesError").field("message", &self.message).field("kind", &self.kind).finish()
         // f.write_str(format!("SerDesError {{\n\tmessage: {}\n}}", self.message).as_str())
     }
 }
 impl Display for SerDesError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("SerDesError").field("message", &self.message).finish()
+        f.debug_struct("SerDesError").field("message", &self.message).field("kind", &self.kind).finish()
     }
 }
 
@@ -1,8 +1,11 @@
 // public
+pub mod checksum;
+pub mod des;
 pub mod des_bytes;
 pub mod des_slice;
 pub mod error;
 pub mod prelude;
+pub mod ser;
 pub mod ser_heap;
 pub mod ser_stack;
 pub mod size;
@@ -1,9 +1,15 @@
-pub use super::des_bytes::{from_bytes, ByteDeserializeBytes, ByteDeserializerBytes};
-pub use super::des_slice::{from_serializer_heap, from_serializer_stack, from_slice, ByteDeserializeSlice, ByteDeserializerSlice};
+pub use super::des::ByteDeserializer;
+pub use super::des_bytes::{from_bytes, from_bytes_with, ByteDeserializeBytes, ByteDeserializeBytesWith, ByteDeserializerBytes};
+pub use super::des_slice::{from_serializer_heap, from_serializer_stack, from_slice, from_slice_with, ByteDeserializeSlice, ByteDeserializeSliceWith, ByteDeserializerSlice};
 // pub use super::error::Result;
-pub use super::error::SerDesError;
-pub use super::ser_heap::{to_bytes_heap, to_serializer_heap};
-pub use super::ser_heap::{ByteSerializeHeap, ByteSerializerHeap};
-pub use super::ser_stack::{to_bytes_stack, to_serializer_stack};
-pub use super::ser_stack::{ByteSerializeStack, ByteSerializerStack};
+pub use super::error::{ErrorKind, SerDesError};
+pub use super::ser::ByteSerializer;
+pub use super::ser_heap::{to_bytes_heap, to_serializer_heap, to_serializer_heap_with};
+pub use super::ser_heap::{ByteSerializeHeap, ByteSerializeHeapWith, ByteSerializerHeap};
+pub use super::ser_stack::{to_bytes_stack, to_bytes_stack_checked, to_serializer_stack, to_serializer_stack_checked, to_serializer_stack_with};
+pub use super::ser_stack::{ByteSerializeStack, ByteSerializeStackWith, ByteSerializerStack, Reserved};
 pub use super::size::{ByteSerializedLenOf, ByteSerializedSizeOf};
+pub use super::utils::len_prefixed::{LenPrefix, LenPrefixedBytes, LenPrefixedString};
+pub use super::utils::numerics::odd_width::{I24, I40, I48, I56, U24, U40, U48, U56};
+pub use super::utils::numerics::wrappers::{Be, Le, Ne};
+pub use super::utils::numerics::Endian;
@@ -0,0 +1,113 @@
+use crate::{
+    prelude::{ByteSerializerHeap, ByteSerializerStack},
+    utils::numerics::{be_bytes::ToBeBytes, le_bytes::ToLeBytes, ne_bytes::ToNeBytes, Endian},
+};
+
+/// Common interface of [ByteSerializerStack] and [ByteSerializerHeap], which allows writing a single function that serializes into either one.
+/// Used by `#[byteserde(with = module)]` attribute of the `byteserde_derive` crate where `module::serialize` is invoked with both.
+/// ```
+/// use byteserde::prelude::*;
+/// fn serialize<S: ByteSerializer>(v: &u16, ser: &mut S) -> byteserde::error::Result<()> {
+///     ser.serialize_be(*v)?;
+///     Ok(())
+/// }
+/// let mut ser_stack = ByteSerializerStack::<16>::default();
+/// serialize(&1, &mut ser_stack).unwrap();
+/// let mut ser_heap = ByteSerializerHeap::default();
+/// serialize(&1, &mut ser_heap).unwrap();
+/// assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+/// ```
+pub trait ByteSerializer {
+    /// Number of bytes serialized so far
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Bytes serialized so far
+    fn as_slice(&self) -> &[u8];
+    /// Protocol version, see [ByteSerializerStack::set_version()]
+    fn version(&self) -> Option<u32>;
+    fn serialize_bytes_slice(&mut self, bytes: &[u8]) -> crate::error::Result<&mut Self>;
+    fn serialize_ne<const N: usize, T: ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self>;
+    fn serialize_le<const N: usize, T: ToLeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self>;
+    fn serialize_be<const N: usize, T: ToBeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self>;
+    /// Byte order, see [ByteSerializerStack::set_endian()]
+    fn endian(&self) -> Endian;
+    fn serialize_endian<const N: usize, T: ToBeBytes<N> + ToLeBytes<N> + ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        match self.endian() {
+            Endian::Big => self.serialize_be(v),
+            Endian::Little => self.serialize_le(v),
+            Endian::Native => self.serialize_ne(v),
+        }
+    }
+}
+
+impl<const CAP: usize> ByteSerializer for ByteSerializerStack<CAP> {
+    #[inline(always)]
+    fn endian(&self) -> Endian {
+        ByteSerializerStack::endian(self)
+    }
+    #[inline(always)]
+    fn version(&self) -> Option<u32> {
+        ByteSerializerStack::version(self)
+    }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        ByteSerializerStack::len(self)
+    }
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        ByteSerializerStack::as_slice(self)
+    }
+    #[inline(always)]
+    fn serialize_bytes_slice(&mut self, bytes: &[u8]) -> crate::error::Result<&mut Self> {
+        ByteSerializerStack::serialize_bytes_slice(self, bytes)
+    }
+    #[inline(always)]
+    fn serialize_ne<const N: usize, T: ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerStack::serialize_ne(self, v)
+    }
+    #[inline(always)]
+    fn serialize_le<const N: usize, T: ToLeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerStack::serialize_le(self, v)
+    }
+    #[inline(always)]
+    fn serialize_be<const N: usize, T: ToBeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerStack::serialize_be(self, v)
+    }
+}
+
+impl ByteSerializer for ByteSerializerHeap {
+    #[inline(always)]
+    fn endian(&self) -> Endian {
+        ByteSerializerHeap::endian(self)
+    }
+    #[inline(always)]
+    fn version(&self) -> Option<u32> {
+        ByteSerializerHeap::version(self)
+    }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        ByteSerializerHeap::len(self)
+    }
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        ByteSerializerHeap::as_slice(self)
+    }
+    #[inline(always)]
+    fn serialize_bytes_slice(&mut self, bytes: &[u8]) -> crate::error::Result<&mut Self> {
+        ByteSerializerHeap::serialize_bytes_slice(self, bytes)
+    }
+    #[inline(always)]
+    fn serialize_ne<const N: usize, T: ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerHeap::serialize_ne(self, v)
+    }
+    #[inline(always)]
+    fn serialize_le<const N: usize, T: ToLeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerHeap::serialize_le(self, v)
+    }
+    #[inline(always)]
+    fn serialize_be<const N: usize, T: ToBeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        ByteSerializerHeap::serialize_be(self, v)
+    }
+}
@@ -1,8 +1,13 @@
 use bytes::{Bytes, BytesMut};
 
-use crate::utils::{
-    hex::{to_hex_line, to_hex_pretty},
-    numerics::{be_bytes::ToBeBytes, le_bytes::ToLeBytes, ne_bytes::ToNeBytes},
+use crate::{
+    error::SerDesError,
+    ser_stack::Reserved,
+    size::ByteSerializedSizeOf,
+    utils::{
+        hex::{to_hex_line, to_hex_pretty},
+        numerics::{be_bytes::ToBeBytes, le_bytes::ToLeBytes, ne_bytes::ToNeBytes, Endian},
+    },
 };
 
 use std::{
@@ -31,6 +36,28 @@ use std::{
 /// ```
 pub trait ByteSerializeHeap {
     fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()>;
+
+    /// Serializes `elements` back to back, used by `Vec<T>` & `[T; N]` impls. `u8` overrides it to copy the whole slice at once.
+    #[inline]
+    fn byte_serialize_heap_slice(elements: &[Self], ser: &mut ByteSerializerHeap) -> crate::error::Result<()>
+    where Self: Sized {
+        for e in elements {
+            e.byte_serialize_heap(ser)?;
+        }
+        Ok(())
+    }
+}
+/// Same as [ByteSerializeHeap] but with access to a user supplied context `C`, ex: negotiated session options.
+/// Implemented by `byteserde_derive` for structs annotated with `#[byteserde(ctx = C)]` and by every [ByteSerializeHeap] type ignoring the context,
+/// hence any member can be nested in a struct with context.
+pub trait ByteSerializeHeapWith<C> {
+    fn byte_serialize_heap_with(&self, ser: &mut ByteSerializerHeap, ctx: &C) -> crate::error::Result<()>;
+}
+impl<C, T: ByteSerializeHeap> ByteSerializeHeapWith<C> for T {
+    #[inline]
+    fn byte_serialize_heap_with(&self, ser: &mut ByteSerializerHeap, _ctx: &C) -> crate::error::Result<()> {
+        self.byte_serialize_heap(ser)
+    }
 }
 /// A byte buffer allocated on heap backed by `Vec<u8>`, can be reused and recycled by calling [Self::clear()].
 /// Example: Create a Buffer and serialize data into it.
@@ -48,10 +75,43 @@ pub trait ByteSerializeHeap {
 #[derive(Debug, Clone, Default)]
 pub struct ByteSerializerHeap {
     bytes: BytesMut,
+    version: Option<u32>,
+    endian: Endian,
 }
 impl ByteSerializerHeap {
     pub fn with_capacity(cap: usize) -> Self {
-        ByteSerializerHeap { bytes: BytesMut::with_capacity(cap) }
+        ByteSerializerHeap {
+            bytes: BytesMut::with_capacity(cap),
+            version: None,
+            endian: Endian::Native,
+        }
+    }
+    /// Protocol version used by members annotated with `#[byteserde(since = N)]` or `#[byteserde(until_version = N)]`, `None` means all of the members are serialized.
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+    /// Sets protocol version, members whose version range does not include it are skipped during serialization.
+    pub fn set_version(&mut self, version: u32) -> &mut Self {
+        self.version = Some(version);
+        self
+    }
+    /// Byte order used by [Self::serialize_endian()], defaults to [Endian::Native]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+    /// Sets byte order used by [Self::serialize_endian()] and members annotated with `#[byteserde(endian = "runtime")]`
+    pub fn set_endian(&mut self, endian: Endian) -> &mut Self {
+        self.endian = endian;
+        self
+    }
+    /// Calls `f` with `endian` set, restoring the current one afterwards, even if `f` fails. `#[derive(..)]` uses it for structs with explicit
+    /// `#[byteserde(endian = "be" | "le" | "ne")]` so that nested members using [Self::serialize_endian()] inherit it
+    pub fn with_endian<R>(&mut self, endian: Endian, f: impl FnOnce(&mut Self) -> crate::error::Result<R>) -> crate::error::Result<R> {
+        let current = self.endian;
+        self.endian = endian;
+        let res = f(self);
+        self.endian = current;
+        res
     }
 }
 /// Provides a convenient way to view buffer content as both HEX and ASCII bytes where printable.
@@ -120,6 +180,7 @@ impl ByteSerializerHeap {
     /// // ... etc
     /// ```
     pub fn serialize_ne<const N: usize, T: ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
     /// This is a convenience method to serialize all rust's numeric primitives into the buffer using `little` endianess.
@@ -133,6 +194,7 @@ impl ByteSerializerHeap {
     /// assert_eq!(ser.len(), 4);
     /// ```
     pub fn serialize_le<const N: usize, T: ToLeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
     /// This is a convenience method to serialize all rust's numeric primitives into the buffer using `big` endianess.
@@ -146,13 +208,105 @@ impl ByteSerializerHeap {
     /// assert_eq!(ser.len(), 4);
     /// ```
     pub fn serialize_be<const N: usize, T: ToBeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
+    /// This is a convenience method to serialize all rust's numeric primitives into the buffer using endianess set by [Self::set_endian()].
+    #[inline]
+    pub fn serialize_endian<const N: usize, T: ToBeBytes<N> + ToLeBytes<N> + ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        match self.endian {
+            Endian::Big => self.serialize_be(v),
+            Endian::Little => self.serialize_le(v),
+            Endian::Native => self.serialize_ne(v),
+        }
+    }
+
+    /// Serializes a `struct` that implements [ByteSerializeHeapWith] trait into the buffer using context `ctx`.
+    pub fn serialize_with<C, T: ByteSerializeHeapWith<C>>(&mut self, v: &T, ctx: &C) -> crate::error::Result<&mut Self> {
+        v.byte_serialize_heap_with(self, ctx)?;
+        Ok(self)
+    }
 
     pub fn serialize<T: ByteSerializeHeap>(&mut self, v: &T) -> crate::error::Result<&mut Self> {
         v.byte_serialize_heap(self)?;
         Ok(self)
     }
+
+    /// Reserves [`ByteSerializedSizeOf::byte_size()`] bytes for a value of type `T` whose value is not yet known, example a length,
+    /// count or a checksum, and returns a [Reserved] handle which must be used to [Self::patch()] the value once it is known.
+    /// ```
+    /// use ::byteserde::prelude::*;
+    /// let mut ser = ByteSerializerHeap::default();
+    /// let len = ser.reserve::<u16>().unwrap();
+    /// ser.serialize_bytes_slice(&[0x01, 0x02, 0x03]).unwrap();
+    /// ser.patch_le(len, ser.bytes_since(len) as u16).unwrap();
+    /// assert_eq!(ser.as_slice(), &[0x03, 0x00, 0x01, 0x02, 0x03]);
+    /// ```
+    pub fn reserve<T: ByteSerializedSizeOf>(&mut self) -> crate::error::Result<Reserved<T>> {
+        let idx = self.bytes.len();
+        self.bytes.resize(idx + T::byte_size(), 0);
+        Ok(Reserved::new(idx))
+    }
+    /// Returns number of bytes written into the buffer after the `reserved` slot.
+    pub fn bytes_since<T: ByteSerializedSizeOf>(&self, reserved: Reserved<T>) -> usize {
+        self.bytes.len() - reserved.end()
+    }
+    /// Overwrites the `reserved` slot using [ByteSerializeHeap] implementation of the `v` value.
+    /// Returns [SerDesError] if number of bytes written is not equal to [`ByteSerializedSizeOf::byte_size()`] of `T`
+    pub fn patch<T: ByteSerializeHeap + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: &T) -> crate::error::Result<&mut Self> {
+        if reserved.end() > self.bytes.len() {
+            return Err(self.error_patch(reserved.idx(), T::byte_size()));
+        }
+        // encode at the tail of the buffer, then move the bytes into the reserved slot
+        let len = self.bytes.len();
+        let res = v.byte_serialize_heap(self);
+        let written = self.bytes.len() - len;
+        if res.is_ok() && written == T::byte_size() {
+            self.bytes.copy_within(len.., reserved.idx());
+        }
+        self.bytes.truncate(len);
+        res?;
+        match written == T::byte_size() {
+            true => Ok(self),
+            false => Err(self.error_patch(reserved.idx(), written)),
+        }
+    }
+    /// Same as [Self::patch()] but for numerics using `native` endianess, see [Self::serialize_ne()]
+    pub fn patch_ne<const N: usize, T: ToNeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using `little` endianess, see [Self::serialize_le()]
+    pub fn patch_le<const N: usize, T: ToLeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using `big` endianess, see [Self::serialize_be()]
+    pub fn patch_be<const N: usize, T: ToBeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using endianess set by [Self::set_endian()], see [Self::serialize_endian()]
+    pub fn patch_endian<const N: usize, T: ToBeBytes<N> + ToLeBytes<N> + ToNeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        match self.endian {
+            Endian::Big => self.patch_be(reserved, v),
+            Endian::Little => self.patch_le(reserved, v),
+            Endian::Native => self.patch_ne(reserved, v),
+        }
+    }
+    fn patch_bytes_slice<T: ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, bytes: &[u8]) -> crate::error::Result<&mut Self> {
+        match reserved.end() > self.bytes.len() || bytes.len() != T::byte_size() {
+            false => {
+                self.bytes[reserved.idx()..reserved.end()].copy_from_slice(bytes);
+                Ok(self)
+            }
+            true => Err(self.error_patch(reserved.idx(), bytes.len())),
+        }
+    }
+    #[cold]
+    fn error_patch(&self, idx: usize, n: usize) -> SerDesError {
+        SerDesError::new(format!("Failed to patch a slice size: {n} at idx: {idx} into {self:x}"))
+    }
 }
 /// Analogous to [to_bytes_heap] but returns an instance of [ByteSerializerHeap]
 pub fn to_serializer_heap<T>(v: &T) -> crate::error::Result<ByteSerializerHeap>
@@ -161,6 +315,13 @@ where T: ByteSerializeHeap {
     v.byte_serialize_heap(&mut ser)?;
     Result::Ok(ser)
 }
+/// Analogous to [to_serializer_heap] but for types implementing [ByteSerializeHeapWith] using context `ctx`
+pub fn to_serializer_heap_with<C, T>(v: &T, ctx: &C) -> crate::error::Result<ByteSerializerHeap>
+where T: ByteSerializeHeapWith<C> {
+    let mut ser = ByteSerializerHeap::default();
+    v.byte_serialize_heap_with(&mut ser, ctx)?;
+    Result::Ok(ser)
+}
 /// Analogous to [to_serializer_heap] but returns an instance of [`Vec<u8>`]
 pub fn to_bytes_heap<T>(v: &T) -> crate::error::Result<Bytes>
 where T: ByteSerializeHeap {
@@ -2,15 +2,17 @@ use bytes::Bytes;
 
 use crate::{
     error::{Result, SerDesError},
+    size::ByteSerializedSizeOf,
     utils::{
         hex::{to_hex_line, to_hex_pretty},
-        numerics::{be_bytes::ToBeBytes, le_bytes::ToLeBytes, ne_bytes::ToNeBytes},
+        numerics::{be_bytes::ToBeBytes, le_bytes::ToLeBytes, ne_bytes::ToNeBytes, Endian},
     },
 };
 
 use std::{
     any::type_name,
     fmt::{Debug, LowerHex},
+    marker::PhantomData,
     mem::MaybeUninit,
 };
 
@@ -38,6 +40,28 @@ use std::{
 /// ```
 pub trait ByteSerializeStack {
     fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()>;
+
+    /// Serializes `elements` back to back, used by `Vec<T>` & `[T; N]` impls. `u8` overrides it to copy the whole slice at once.
+    #[inline]
+    fn byte_serialize_stack_slice<const CAP: usize>(elements: &[Self], ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()>
+    where Self: Sized {
+        for e in elements {
+            e.byte_serialize_stack(ser)?;
+        }
+        Ok(())
+    }
+}
+/// Same as [ByteSerializeStack] but with access to a user supplied context `C`, ex: negotiated session options.
+/// Implemented by `byteserde_derive` for structs annotated with `#[byteserde(ctx = C)]` and by every [ByteSerializeStack] type ignoring the context,
+/// hence any member can be nested in a struct with context.
+pub trait ByteSerializeStackWith<C> {
+    fn byte_serialize_stack_with<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>, ctx: &C) -> crate::error::Result<()>;
+}
+impl<C, T: ByteSerializeStack> ByteSerializeStackWith<C> for T {
+    #[inline]
+    fn byte_serialize_stack_with<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>, _ctx: &C) -> crate::error::Result<()> {
+        self.byte_serialize_stack(ser)
+    }
 }
 /// A byte buffer allocated on stack backed by `[u8; CAP]`, can be reused and recycled by calling [Self::clear()].
 /// Example: Creates a buffer with 128 bytes capacity and serializes data into it.
@@ -60,6 +84,8 @@ pub trait ByteSerializeStack {
 pub struct ByteSerializerStack<const CAP: usize> {
     bytes: [u8; CAP],
     len: usize,
+    version: Option<u32>,
+    endian: Endian,
 }
 /// Provides a convenient way to view buffer content as both HEX and ASCII bytes where printable.
 /// supports both forms of alternate formatting `{:x}` and `{:#x}`.
@@ -87,6 +113,8 @@ impl<const CAP: usize> Default for ByteSerializerStack<CAP> {
         ByteSerializerStack {
             bytes: unsafe { MaybeUninit::uninit().assume_init() },
             len: 0,
+            version: None,
+            endian: Endian::Native,
         }
     }
 }
@@ -103,6 +131,33 @@ impl<const CAP: usize> ByteSerializerStack<CAP> {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+    /// Protocol version used by members annotated with `#[byteserde(since = N)]` or `#[byteserde(until_version = N)]`, `None` means all of the members are serialized.
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+    /// Sets protocol version, members whose version range does not include it are skipped during serialization.
+    pub fn set_version(&mut self, version: u32) -> &mut Self {
+        self.version = Some(version);
+        self
+    }
+    /// Byte order used by [Self::serialize_endian()], defaults to [Endian::Native]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+    /// Sets byte order used by [Self::serialize_endian()] and members annotated with `#[byteserde(endian = "runtime")]`
+    pub fn set_endian(&mut self, endian: Endian) -> &mut Self {
+        self.endian = endian;
+        self
+    }
+    /// Calls `f` with `endian` set, restoring the current one afterwards, even if `f` fails. `#[derive(..)]` uses it for structs with explicit
+    /// `#[byteserde(endian = "be" | "le" | "ne")]` so that nested members using [Self::serialize_endian()] inherit it
+    pub fn with_endian<R>(&mut self, endian: Endian, f: impl FnOnce(&mut Self) -> crate::error::Result<R>) -> crate::error::Result<R> {
+        let current = self.endian;
+        self.endian = endian;
+        let res = f(self);
+        self.endian = current;
+        res
+    }
     /// Returns const capacity of the buffer.
     pub fn capacity(&self) -> usize {
         CAP
@@ -136,9 +191,7 @@ impl<const CAP: usize> ByteSerializerStack<CAP> {
     }
 
     fn error(&self, n: usize) -> SerDesError {
-        SerDesError {
-            message: format!("Failed to add a slice size: {n} into {self:x}",),
-        }
+        SerDesError::new(format!("Failed to add a slice size: {n} into {self:x}",))
     }
     /// This is a convenience method to serialize all rust's numeric primitives into the buffer using `native` endianess.
     /// ToNeBytes trait is already implemented for all rust's numeric primitives in this crate
@@ -150,6 +203,7 @@ impl<const CAP: usize> ByteSerializerStack<CAP> {
     /// // ... etc
     /// ```
     pub fn serialize_ne<const N: usize, T: ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
     /// This is a convenience method to serialize all rust's numeric primitives into the buffer using `little` endianess.
@@ -162,6 +216,7 @@ impl<const CAP: usize> ByteSerializerStack<CAP> {
     /// // ... etc
     /// ```
     pub fn serialize_le<const N: usize, T: ToLeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
     /// This is a convenience method to serialize all rust's numeric primitives into the buffer using `big` endianess.
@@ -174,13 +229,136 @@ impl<const CAP: usize> ByteSerializerStack<CAP> {
     /// // ... etc
     /// ```
     pub fn serialize_be<const N: usize, T: ToBeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
         self.serialize_bytes_slice(&v.to_bytes())
     }
+    /// This is a convenience method to serialize all rust's numeric primitives into the buffer using endianess set by [Self::set_endian()].
+    #[inline]
+    pub fn serialize_endian<const N: usize, T: ToBeBytes<N> + ToLeBytes<N> + ToNeBytes<N>>(&mut self, v: T) -> crate::error::Result<&mut Self> {
+        match self.endian {
+            Endian::Big => self.serialize_be(v),
+            Endian::Little => self.serialize_le(v),
+            Endian::Native => self.serialize_ne(v),
+        }
+    }
     /// Serializes a `struct` that implements [ByteSerializeStack] trait into the buffer.
     pub fn serialize<T: ByteSerializeStack>(&mut self, v: &T) -> crate::error::Result<&mut Self> {
         v.byte_serialize_stack(self)?;
         Ok(self)
     }
+    /// Serializes a `struct` that implements [ByteSerializeStackWith] trait into the buffer using context `ctx`.
+    pub fn serialize_with<C, T: ByteSerializeStackWith<C>>(&mut self, v: &T, ctx: &C) -> crate::error::Result<&mut Self> {
+        v.byte_serialize_stack_with(self, ctx)?;
+        Ok(self)
+    }
+
+    /// Reserves [`ByteSerializedSizeOf::byte_size()`] bytes for a value of type `T` whose value is not yet known, example a length,
+    /// count or a checksum, and returns a [Reserved] handle which must be used to [Self::patch()] the value once it is known.
+    /// ```
+    /// use ::byteserde::prelude::*;
+    /// let mut ser = ByteSerializerStack::<128>::default();
+    /// let len = ser.reserve::<u16>().unwrap();
+    /// ser.serialize_bytes_slice(&[0x01, 0x02, 0x03]).unwrap();
+    /// ser.patch_be(len, ser.bytes_since(len) as u16).unwrap();
+    /// assert_eq!(ser.as_slice(), &[0x00, 0x03, 0x01, 0x02, 0x03]);
+    /// ```
+    pub fn reserve<T: ByteSerializedSizeOf>(&mut self) -> crate::error::Result<Reserved<T>> {
+        let idx = self.len;
+        match T::byte_size() > self.avail() {
+            false => {
+                self.bytes[idx..idx + T::byte_size()].fill(0);
+                self.len += T::byte_size();
+                Ok(Reserved::new(idx))
+            }
+            true => Err(self.error(T::byte_size())),
+        }
+    }
+    /// Returns number of bytes written into the buffer after the `reserved` slot.
+    pub fn bytes_since<T: ByteSerializedSizeOf>(&self, reserved: Reserved<T>) -> usize {
+        self.len - reserved.end()
+    }
+    /// Overwrites the `reserved` slot using [ByteSerializeStack] implementation of the `v` value.
+    /// Returns [SerDesError] if number of bytes written is not equal to [`ByteSerializedSizeOf::byte_size()`] of `T`
+    pub fn patch<T: ByteSerializeStack + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: &T) -> crate::error::Result<&mut Self> {
+        if reserved.end() > self.len {
+            return Err(self.error_patch(reserved.idx(), T::byte_size()));
+        }
+        let len = self.len;
+        self.len = reserved.idx();
+        let res = v.byte_serialize_stack(self);
+        let written = self.len - reserved.idx();
+        self.len = len;
+        res?;
+        match written == T::byte_size() {
+            true => Ok(self),
+            false => Err(self.error_patch(reserved.idx(), written)),
+        }
+    }
+    /// Same as [Self::patch()] but for numerics using `native` endianess, see [Self::serialize_ne()]
+    pub fn patch_ne<const N: usize, T: ToNeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using `little` endianess, see [Self::serialize_le()]
+    pub fn patch_le<const N: usize, T: ToLeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using `big` endianess, see [Self::serialize_be()]
+    pub fn patch_be<const N: usize, T: ToBeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        v.check_width()?;
+        self.patch_bytes_slice(reserved, &v.to_bytes())
+    }
+    /// Same as [Self::patch()] but for numerics using endianess set by [Self::set_endian()], see [Self::serialize_endian()]
+    pub fn patch_endian<const N: usize, T: ToBeBytes<N> + ToLeBytes<N> + ToNeBytes<N> + ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, v: T) -> crate::error::Result<&mut Self> {
+        match self.endian {
+            Endian::Big => self.patch_be(reserved, v),
+            Endian::Little => self.patch_le(reserved, v),
+            Endian::Native => self.patch_ne(reserved, v),
+        }
+    }
+    fn patch_bytes_slice<T: ByteSerializedSizeOf>(&mut self, reserved: Reserved<T>, bytes: &[u8]) -> crate::error::Result<&mut Self> {
+        match reserved.end() > self.len || bytes.len() != T::byte_size() {
+            false => {
+                self.bytes[reserved.idx()..reserved.end()].copy_from_slice(bytes);
+                Ok(self)
+            }
+            true => Err(self.error_patch(reserved.idx(), bytes.len())),
+        }
+    }
+    #[cold]
+    fn error_patch(&self, idx: usize, n: usize) -> SerDesError {
+        SerDesError::new(format!("Failed to patch a slice size: {n} at idx: {idx} into {self:x}"))
+    }
+}
+
+/// A handle to a slot in the serializer buffer returned by [`ByteSerializerStack::reserve()`] or [`crate::ser_heap::ByteSerializerHeap::reserve()`]
+/// which is sized for a value of type `T` and is intended to be back filled once the value is known, example a length, count or a checksum.
+#[derive(Debug, PartialEq)]
+pub struct Reserved<T> {
+    idx: usize,
+    ty: PhantomData<T>,
+}
+impl<T> Clone for Reserved<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Reserved<T> {}
+impl<T> Reserved<T> {
+    pub(crate) fn new(idx: usize) -> Self {
+        Reserved { idx, ty: PhantomData }
+    }
+    /// Index of the first byte of the reserved slot in the serializer buffer.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+}
+impl<T: ByteSerializedSizeOf> Reserved<T> {
+    /// Index of the first byte after the reserved slot in the serializer buffer.
+    pub fn end(&self) -> usize {
+        self.idx + T::byte_size()
+    }
 }
 
 /// Analogous to [`to_bytes_stack::<CAP>()`], but returns an instance of [`ByteSerializerStack<CAP>`].
@@ -192,6 +370,14 @@ where T: ByteSerializeStack {
     v.byte_serialize_stack(&mut ser)?;
     Result::Ok(ser)
 }
+/// Analogous to [`to_serializer_stack::<CAP>()`] but for types implementing [ByteSerializeStackWith] using context `ctx`
+#[inline]
+pub fn to_serializer_stack_with<const CAP: usize, C, T>(v: &T, ctx: &C) -> crate::error::Result<ByteSerializerStack<CAP>>
+where T: ByteSerializeStackWith<C> {
+    let mut ser = ByteSerializerStack::<CAP>::default();
+    v.byte_serialize_stack_with(&mut ser, ctx)?;
+    Result::Ok(ser)
+}
 /// Analogous to [`to_serializer_stack::<CAP>()`], but returns just the array of bytes `[u8; CAP]`.
 /// Note that this is not a `&[u8]` slice, but an array of bytes with length CAP even if
 /// the actual length of the serialized data is less.
@@ -203,6 +389,65 @@ where T: ByteSerializeStack {
     Ok((ser.bytes, ser.len()))
 }
 
+/// Evaluates at compile time that a buffer of `CAP` bytes can hold `T`, referencing [Self::OK] fails the build when it can't.
+struct AssertCapacity<const CAP: usize, T>(PhantomData<T>);
+impl<const CAP: usize, T: ByteSerializedSizeOf> AssertCapacity<CAP, T> {
+    const OK: () = assert!(
+        CAP >= T::BYTE_SIZE,
+        "ByteSerializerStack<CAP> capacity is less than ByteSerializedSizeOf::BYTE_SIZE of the type being serialized or the type does not define BYTE_SIZE"
+    );
+}
+
+/// Analogous to [`to_serializer_stack::<CAP>()`], but fails to compile if `CAP` is less than [`ByteSerializedSizeOf::BYTE_SIZE`] of `T`.
+/// Note that the check is evaluated during code generation, hence it is reported by `cargo build` but not `cargo check`.
+/// ```
+/// use ::byteserde::prelude::*;
+///
+/// struct MyStruct { a: u16, }
+/// impl ByteSerializeStack for MyStruct {
+///     fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> byteserde::error::Result<()> {
+///         ser.serialize_be(self.a)?;
+///         Ok(())
+///    }
+/// }
+/// impl ByteSerializedSizeOf for MyStruct {
+///     const BYTE_SIZE: usize = 2;
+///     fn byte_size() -> usize { Self::BYTE_SIZE }
+/// }
+///
+/// let ser: ByteSerializerStack<2> = to_serializer_stack_checked(&MyStruct { a: 1 }).unwrap();
+/// assert_eq!(ser.len(), 2);
+/// ```
+/// ```compile_fail
+/// # use ::byteserde::prelude::*;
+/// # struct MyStruct { a: u16, }
+/// # impl ByteSerializeStack for MyStruct {
+/// #     fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> byteserde::error::Result<()> {
+/// #         ser.serialize_be(self.a)?;
+/// #         Ok(())
+/// #    }
+/// # }
+/// # impl ByteSerializedSizeOf for MyStruct {
+/// #     const BYTE_SIZE: usize = 2;
+/// #     fn byte_size() -> usize { Self::BYTE_SIZE }
+/// # }
+/// let ser: ByteSerializerStack<1> = to_serializer_stack_checked(&MyStruct { a: 1 }).unwrap();
+/// ```
+#[inline]
+pub fn to_serializer_stack_checked<const CAP: usize, T>(v: &T) -> crate::error::Result<ByteSerializerStack<CAP>>
+where T: ByteSerializeStack + ByteSerializedSizeOf {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertCapacity::<CAP, T>::OK;
+    to_serializer_stack(v)
+}
+/// Analogous to [`to_bytes_stack::<CAP>()`], but fails to compile if `CAP` is less than [`ByteSerializedSizeOf::BYTE_SIZE`] of `T`.
+#[inline]
+pub fn to_bytes_stack_checked<const CAP: usize, T>(v: &T) -> crate::error::Result<([u8; CAP], usize)>
+where T: ByteSerializeStack + ByteSerializedSizeOf {
+    let ser = to_serializer_stack_checked(v)?;
+    Ok((ser.bytes, ser.len()))
+}
+
 impl ByteSerializeStack for Bytes {
     fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
         ser.serialize_bytes_slice(&self[..])?;
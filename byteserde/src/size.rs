@@ -5,16 +5,29 @@
 ///
 /// # Guarantees
 /// * Must return the number of bytes the implementing structure will occupy in a byte stream
-///  when serialized. This is instance `independent` trait and may not be possible to implement for
-/// `struct`s whose elements might be allocated on the heap at run time, example String, Vec, etc.
+///   when serialized. This is instance `independent` trait and may not be possible to implement for
+///   `struct`s whose elements might be allocated on the heap at run time, example String, Vec, etc.
+/// * [Self::BYTE_SIZE] when defined must be equal to [Self::byte_size()], it exists so that the size can be used in `const` context,
+///   example [crate::ser_stack::to_serializer_stack_checked] uses it to verify `CAP` at compile time.
 pub trait ByteSerializedSizeOf {
+    /// Defaults to `usize::MAX` for implementations which only define [Self::byte_size()], such types and derived types
+    /// with such members can't be used with [crate::ser_stack::to_serializer_stack_checked]
+    const BYTE_SIZE: usize = usize::MAX;
     fn byte_size() -> usize;
 }
 macro_rules! size_of {
     ($t:ty) => {
         impl ByteSerializedSizeOf for $t {
+            const BYTE_SIZE: usize = std::mem::size_of::<$t>();
+            #[inline(always)]
             fn byte_size() -> usize {
-                std::mem::size_of::<$t>()
+                Self::BYTE_SIZE
+            }
+        }
+        impl ByteSerializedLenOf for $t {
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                Self::BYTE_SIZE
             }
         }
     };
@@ -31,9 +44,14 @@ size_of!(u128);
 size_of!(i128);
 size_of!(usize);
 size_of!(isize);
+size_of!(f32);
+size_of!(f64);
+size_of!(bool);
 
 /// Returns strictly the size of the type `T` in bytes and ignores the None variant
 impl<T: ByteSerializedSizeOf> ByteSerializedSizeOf for Option<T> {
+    const BYTE_SIZE: usize = T::BYTE_SIZE;
+    #[inline(always)]
     fn byte_size() -> usize {
         T::byte_size()
     }
@@ -45,15 +63,16 @@ impl<T: ByteSerializedSizeOf> ByteSerializedSizeOf for Option<T> {
 ///
 /// # Guarantees
 /// * Must return the number of bytes a specific `instance` of implementing structure will occupy in a byte stream
-/// when serialized. This is instance `dependent` trait and might return a differet length for each instance,
-/// example String, Vec, etc.
+///   when serialized. This is instance `dependent` trait and might return a differet length for each instance,
+///   example String, Vec, etc.
 pub trait ByteSerializedLenOf {
     fn byte_len(&self) -> usize;
 }
 
+/// Includes the `u32` length prefix, see [LenPrefixedString](crate::utils::len_prefixed::LenPrefixedString)
 impl ByteSerializedLenOf for String {
     fn byte_len(&self) -> usize {
-        self.len()
+        std::mem::size_of::<u32>() + self.len()
     }
 }
 impl ByteSerializedLenOf for char {
@@ -0,0 +1,29 @@
+/// Position of the first occurrence of `terminator` in `bytes` or `None` if it is not found or `terminator` is empty
+/// ```
+/// use byteserde::utils::bytes::position_of;
+/// assert_eq!(position_of(b"abc\r\ndef", b"\r\n"), Some(3));
+/// assert_eq!(position_of(b"abc", &[0x00]), None);
+/// assert_eq!(position_of(b"abc", &[]), None);
+/// ```
+pub fn position_of(bytes: &[u8], terminator: &[u8]) -> Option<usize> {
+    match terminator.len() {
+        0 => None,
+        1 => bytes.iter().position(|b| *b == terminator[0]),
+        n => bytes.windows(n).position(|w| w == terminator),
+    }
+}
+
+/// `true` when a reader looking for `terminator` at the start of `bytes` would find it, given that `bytes` are followed by `terminator`
+/// ```
+/// use byteserde::utils::bytes::starts_with_terminator;
+/// assert!(starts_with_terminator(&[0xFF, 0xFF, 0x01], &[0xFF, 0xFF]));
+/// assert!(starts_with_terminator(&[0xFF], &[0xFF, 0xFF]));
+/// assert!(!starts_with_terminator(&[0x00, 0xFF], &[0xFF, 0xFF]));
+/// assert!(!starts_with_terminator(&[0xFF], &[0xFF, 0x00]));
+/// ```
+pub fn starts_with_terminator(bytes: &[u8], terminator: &[u8]) -> bool {
+    match bytes.len() >= terminator.len() {
+        true => bytes.starts_with(terminator),
+        false => terminator.starts_with(bytes) && terminator[bytes.len()..] == terminator[..terminator.len() - bytes.len()],
+    }
+}
@@ -51,6 +51,7 @@ mod tests {
     use text_diff::{diff, print_diff};
 
     #[test]
+    #[allow(clippy::invisible_characters)]
     fn hex() {
         unittest::setup::log::configure();
         let mut v: Vec<u8> = Vec::new();
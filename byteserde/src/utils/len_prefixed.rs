@@ -0,0 +1,249 @@
+//! Length prefixed `String` & `Vec<u8>` whose prefix width & endianess is explicit in the type, hence the byte stream is
+//! identical irrespective of the platform it was produced on.
+//!
+//! # Wire convention
+//! * `L` length prefix holding the number of bytes that follow, ex: `u16` is `2` bytes `big` endian, `Le<u16>` is `2` bytes `little` endian
+//! * followed by the `utf-8` bytes of the `String` or the raw bytes of the `Vec<u8>`
+//!
+//! Default `String` impls use the same convention with a `u32` `big` endian prefix, ie. `String` & `LenPrefixedString<u32>` are interchangeable.
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    des::ByteDeserializer,
+    des_bytes::{ByteDeserializeBytes, ByteDeserializerBytes},
+    des_slice::{ByteDeserializeSlice, ByteDeserializerSlice},
+    error::SerDesError,
+    ser::ByteSerializer,
+    ser_heap::{ByteSerializeHeap, ByteSerializerHeap},
+    ser_stack::{ByteSerializeStack, ByteSerializerStack},
+    size::{ByteSerializedLenOf, ByteSerializedSizeOf},
+    utils::{
+        hex::to_hex_line,
+        numerics::wrappers::{Be, Le},
+    },
+};
+
+/// Width & endianess of the length prefix used by [LenPrefixedString] & [LenPrefixedBytes].
+/// Implemented for `u8`, `u16`, `u32` & `u64` using `big` endian as well as [`Be<T>`] & [`Le<T>`] wrappers of the same.
+pub trait LenPrefix: ByteSerializedSizeOf + Sized {
+    /// Returns [SerDesError] if `len` does not fit into the prefix
+    fn try_from_len(len: usize) -> crate::error::Result<Self>;
+    /// Returns [SerDesError] if the prefix does not fit into `usize` on this platform
+    fn try_into_len(self) -> crate::error::Result<usize>;
+    fn serialize<S: ByteSerializer>(self, ser: &mut S) -> crate::error::Result<()>;
+    fn deserialize<D: ByteDeserializer>(des: &mut D) -> crate::error::Result<Self>;
+}
+
+#[cold]
+fn error_len<L>(len: impl Display) -> SerDesError {
+    SerDesError::new(format!("length {len} does not fit into {} length prefix", std::any::type_name::<L>()))
+}
+
+/// calling
+///     impl_len_prefix!(u16);
+/// will generate [LenPrefix] impls for `u16`, `Be<u16>` & `Le<u16>`
+macro_rules! impl_len_prefix {
+    ($($ty:ty),+) => {
+        $(
+        impl LenPrefix for $ty {
+            #[inline]
+            fn try_from_len(len: usize) -> crate::error::Result<Self> {
+                <$ty>::try_from(len).map_err(|_| error_len::<Self>(len))
+            }
+            #[inline]
+            fn try_into_len(self) -> crate::error::Result<usize> {
+                usize::try_from(self).map_err(|_| error_len::<usize>(self))
+            }
+            #[inline]
+            fn serialize<S: ByteSerializer>(self, ser: &mut S) -> crate::error::Result<()> {
+                ser.serialize_be(self)?;
+                Ok(())
+            }
+            #[inline]
+            fn deserialize<D: ByteDeserializer>(des: &mut D) -> crate::error::Result<Self> {
+                des.deserialize_be()
+            }
+        }
+        impl LenPrefix for Be<$ty> {
+            #[inline]
+            fn try_from_len(len: usize) -> crate::error::Result<Self> {
+                <$ty>::try_from(len).map(Be).map_err(|_| error_len::<Self>(len))
+            }
+            #[inline]
+            fn try_into_len(self) -> crate::error::Result<usize> {
+                self.0.try_into_len()
+            }
+            #[inline]
+            fn serialize<S: ByteSerializer>(self, ser: &mut S) -> crate::error::Result<()> {
+                ser.serialize_be(self.0)?;
+                Ok(())
+            }
+            #[inline]
+            fn deserialize<D: ByteDeserializer>(des: &mut D) -> crate::error::Result<Self> {
+                Ok(Be(des.deserialize_be()?))
+            }
+        }
+        impl LenPrefix for Le<$ty> {
+            #[inline]
+            fn try_from_len(len: usize) -> crate::error::Result<Self> {
+                <$ty>::try_from(len).map(Le).map_err(|_| error_len::<Self>(len))
+            }
+            #[inline]
+            fn try_into_len(self) -> crate::error::Result<usize> {
+                self.0.try_into_len()
+            }
+            #[inline]
+            fn serialize<S: ByteSerializer>(self, ser: &mut S) -> crate::error::Result<()> {
+                ser.serialize_le(self.0)?;
+                Ok(())
+            }
+            #[inline]
+            fn deserialize<D: ByteDeserializer>(des: &mut D) -> crate::error::Result<Self> {
+                Ok(Le(des.deserialize_le()?))
+            }
+        }
+        )+
+    };
+}
+impl_len_prefix!(u8, u16, u32, u64);
+
+/// Writes `L` length prefix followed by the `bytes`
+#[inline]
+pub(crate) fn serialize_len_prefixed<L: LenPrefix, S: ByteSerializer>(bytes: &[u8], ser: &mut S) -> crate::error::Result<()> {
+    L::try_from_len(bytes.len())?.serialize(ser)?;
+    ser.serialize_bytes_slice(bytes)?;
+    Ok(())
+}
+/// Reads `L` length prefix and depletes that many bytes
+#[inline]
+pub(crate) fn deserialize_len_prefixed<L: LenPrefix, D: ByteDeserializer>(des: &mut D) -> crate::error::Result<&[u8]> {
+    let len = L::deserialize(des)?.try_into_len()?;
+    des.deserialize_bytes_slice(len)
+}
+/// Same as [deserialize_len_prefixed] but also validates that the bytes are `utf-8`
+#[inline]
+pub(crate) fn deserialize_len_prefixed_string<L: LenPrefix, D: ByteDeserializer>(des: &mut D) -> crate::error::Result<String> {
+    let bytes = deserialize_len_prefixed::<L, D>(des)?;
+    match String::from_utf8(bytes.to_vec()) {
+        Ok(s) => Ok(s),
+        Err(_) => Err(SerDesError::new(format!("bytes slice is not a valid utf8 string bytes: {}", to_hex_line(bytes)))),
+    }
+}
+
+/// calling
+///     len_prefixed!(LenPrefixedString, String, deserialize_len_prefixed_string);
+/// will generate `LenPrefixedString<L>` type holding a `String` with its constructors, conversions, [Deref], serialize,
+/// deserialize & len trait impls
+macro_rules! len_prefixed {
+    ($(#[$meta:meta])* $name:ident, $inner:ty, $des:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<L: LenPrefix = u32> {
+            value: $inner,
+            prefix: PhantomData<L>,
+        }
+        impl<L: LenPrefix> $name<L> {
+            #[inline(always)]
+            pub fn new(value: $inner) -> Self {
+                $name { value, prefix: PhantomData }
+            }
+            #[inline(always)]
+            pub fn into_inner(self) -> $inner {
+                self.value
+            }
+        }
+        impl<L: LenPrefix> From<$inner> for $name<L> {
+            #[inline(always)]
+            fn from(value: $inner) -> Self {
+                Self::new(value)
+            }
+        }
+        impl<L: LenPrefix> Deref for $name<L> {
+            type Target = $inner;
+            #[inline(always)]
+            fn deref(&self) -> &$inner {
+                &self.value
+            }
+        }
+        impl<L: LenPrefix> DerefMut for $name<L> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut $inner {
+                &mut self.value
+            }
+        }
+        impl<L: LenPrefix> ByteSerializeStack for $name<L> {
+            #[inline]
+            fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+                serialize_len_prefixed::<L, _>(self.value.as_ref(), ser)
+            }
+        }
+        impl<L: LenPrefix> ByteSerializeHeap for $name<L> {
+            #[inline]
+            fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+                serialize_len_prefixed::<L, _>(self.value.as_ref(), ser)
+            }
+        }
+        impl<L: LenPrefix> ByteDeserializeSlice<$name<L>> for $name<L> {
+            #[inline]
+            fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<$name<L>> {
+                Ok(Self::new($des(des)?))
+            }
+        }
+        impl<L: LenPrefix> ByteDeserializeBytes<$name<L>> for $name<L> {
+            #[inline]
+            fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<$name<L>> {
+                Ok(Self::new($des(des)?))
+            }
+        }
+        impl<L: LenPrefix> ByteSerializedLenOf for $name<L> {
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                L::byte_size() + self.value.len()
+            }
+        }
+    };
+}
+
+len_prefixed!(
+    /// `String` with an explicit length prefix `L`, defaults to `u32` `big` endian
+    /// ```
+    /// use byteserde::prelude::*;
+    /// let inp = LenPrefixedString::<Le<u16>>::from("hi".to_string());
+    /// let ser: ByteSerializerStack<8> = to_serializer_stack(&inp).unwrap();
+    /// assert_eq!(ser.as_slice(), &[0x02, 0x00, b'h', b'i']);
+    /// let out: LenPrefixedString<Le<u16>> = from_slice(ser.as_slice()).unwrap();
+    /// assert_eq!(out.as_str(), "hi");
+    /// ```
+    LenPrefixedString,
+    String,
+    |des| deserialize_len_prefixed_string::<L, _>(des)
+);
+len_prefixed!(
+    /// `Vec<u8>` with an explicit length prefix `L`, defaults to `u32` `big` endian
+    /// ```
+    /// use byteserde::prelude::*;
+    /// let inp = LenPrefixedBytes::<u8>::from(vec![1, 2]);
+    /// let ser: ByteSerializerStack<8> = to_serializer_stack(&inp).unwrap();
+    /// assert_eq!(ser.as_slice(), &[0x02, 0x01, 0x02]);
+    /// assert!(to_serializer_heap(&LenPrefixedBytes::<u8>::from(vec![0; 256])).is_err());
+    /// ```
+    LenPrefixedBytes,
+    Vec<u8>,
+    |des| deserialize_len_prefixed::<L, _>(des).map(|bytes| bytes.to_vec())
+);
+
+impl<L: LenPrefix> From<&str> for LenPrefixedString<L> {
+    #[inline(always)]
+    fn from(value: &str) -> Self {
+        Self::new(value.to_string())
+    }
+}
+impl<L: LenPrefix> Display for LenPrefixedString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
@@ -1,3 +1,6 @@
+pub mod bytes;
 pub mod hex;
+pub mod len_prefixed;
 pub mod numerics;
+pub mod std_types;
 pub mod strings;
@@ -1,5 +1,10 @@
 pub trait ToBeBytes<const N: usize> {
     fn to_bytes(&self) -> [u8; N];
+    /// Returns [SerDesError](crate::error::SerDesError) if the value does not fit into `N` bytes, only applies to odd width types like [U24](crate::utils::numerics::odd_width::U24)
+    #[inline(always)]
+    fn check_width(&self) -> crate::error::Result<()> {
+        Ok(())
+    }
 }
 
 /// calling
@@ -21,6 +26,8 @@ macro_rules! impl_ToBeBytes {
     };
 }
 const USIZE: usize = std::mem::size_of::<usize>();
+impl_ToBeBytes!(u8, 1);
+impl_ToBeBytes!(i8, 1);
 impl_ToBeBytes!(u16, 2);
 impl_ToBeBytes!(i16, 2);
 impl_ToBeBytes!(u32, 4);
@@ -63,6 +70,8 @@ macro_rules! impl_FromBeBytes {
     };
 }
 
+impl_FromBeBytes!(u8, 1);
+impl_FromBeBytes!(i8, 1);
 impl_FromBeBytes!(u16, 2);
 impl_FromBeBytes!(i16, 2);
 impl_FromBeBytes!(u32, 4);
@@ -1,5 +1,10 @@
 pub trait ToLeBytes<const N: usize> {
     fn to_bytes(&self) -> [u8; N];
+    /// Returns [SerDesError](crate::error::SerDesError) if the value does not fit into `N` bytes, only applies to odd width types like [U24](crate::utils::numerics::odd_width::U24)
+    #[inline(always)]
+    fn check_width(&self) -> crate::error::Result<()> {
+        Ok(())
+    }
 }
 
 /// calling
@@ -22,6 +27,8 @@ macro_rules! impl_ToLeBytes {
 }
 
 const USIZE: usize = std::mem::size_of::<usize>();
+impl_ToLeBytes!(u8, 1);
+impl_ToLeBytes!(i8, 1);
 impl_ToLeBytes!(u16, 2);
 impl_ToLeBytes!(i16, 2);
 impl_ToLeBytes!(u32, 4);
@@ -1,3 +1,16 @@
 pub mod be_bytes;
 pub mod le_bytes;
 pub mod ne_bytes;
+pub mod odd_width;
+pub mod primitives;
+pub mod wrappers;
+
+/// Byte order used by [`serialize_endian()`](crate::ser_stack::ByteSerializerStack::serialize_endian) & [`deserialize_endian()`](crate::des_slice::ByteDeserializerSlice::deserialize_endian),
+/// set at run time using `set_endian()` on serializers & deserializers, defaults to [Endian::Native]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    Big,
+    Little,
+    #[default]
+    Native,
+}
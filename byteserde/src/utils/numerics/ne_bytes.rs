@@ -1,5 +1,10 @@
 pub trait ToNeBytes<const N: usize> {
     fn to_bytes(&self) -> [u8; N];
+    /// Returns [SerDesError](crate::error::SerDesError) if the value does not fit into `N` bytes, only applies to odd width types like [U24](crate::utils::numerics::odd_width::U24)
+    #[inline(always)]
+    fn check_width(&self) -> crate::error::Result<()> {
+        Ok(())
+    }
 }
 
 /// calling
@@ -0,0 +1,247 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    error::SerDesError,
+    size::{ByteSerializedLenOf, ByteSerializedSizeOf},
+};
+
+/// calling
+///     odd_width!(U24, u32, 3);
+/// will generate a `U24(pub u32)` numeric which occupies `3` bytes in the byte stream, its [MIN](U24::MIN) & [MAX](U24::MAX)
+/// and the `ToBeBytes`, `ToLeBytes`, `ToNeBytes`, `FromBeBytes`, `FromLeBytes`, `FromNeBytes` impls so that it can be used with
+/// `serialize_be/le/ne` & `deserialize_be/le/ne` like any other rust numeric primitive.
+/// * serialization fails if the inner value is outside of `[MIN, MAX]` range
+/// * deserialization sign extends signed variants, ex: `I24` bytes `[0xFF, 0xFF, 0xFE]` yield `-2`
+macro_rules! odd_width {
+    ($(#[$meta:meta])* $name:ident, $inner:ty, $len:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name(pub $inner);
+
+        impl $name {
+            /// Number of bytes occupied in the byte stream
+            pub const LEN: usize = $len;
+            /// Number of unused high order bits of the inner type
+            const SHIFT: u32 = <$inner>::BITS - ($len * 8);
+            pub const MIN: $inner = <$inner>::MIN >> Self::SHIFT;
+            pub const MAX: $inner = <$inner>::MAX >> Self::SHIFT;
+            const INNER_LEN: usize = std::mem::size_of::<$inner>();
+
+            #[inline(always)]
+            pub fn is_valid(&self) -> bool {
+                self.0 >= Self::MIN && self.0 <= Self::MAX
+            }
+            #[inline]
+            fn check_width(&self) -> crate::error::Result<()> {
+                match self.is_valid() {
+                    true => Ok(()),
+                    false => Err(Self::error_range(self.0)),
+                }
+            }
+            #[cold]
+            fn error_range(v: $inner) -> SerDesError {
+                SerDesError::new(format!("{} value {} is out of range [{}, {}]", stringify!($name), v, Self::MIN, Self::MAX))
+            }
+            #[inline(always)]
+            fn be_bytes(&self) -> [u8; $len] {
+                self.0.to_be_bytes()[Self::INNER_LEN - $len..].try_into().unwrap()
+            }
+            #[inline(always)]
+            fn le_bytes(&self) -> [u8; $len] {
+                self.0.to_le_bytes()[..$len].try_into().unwrap()
+            }
+            #[inline(always)]
+            fn from_be(v: &[u8; $len]) -> Self {
+                let mut bytes = [0_u8; std::mem::size_of::<$inner>()];
+                bytes[Self::INNER_LEN - $len..].copy_from_slice(v);
+                $name((<$inner>::from_be_bytes(bytes) << Self::SHIFT) >> Self::SHIFT)
+            }
+            #[inline(always)]
+            fn from_le(v: &[u8; $len]) -> Self {
+                let mut bytes = [0_u8; std::mem::size_of::<$inner>()];
+                bytes[..$len].copy_from_slice(v);
+                $name((<$inner>::from_le_bytes(bytes) << Self::SHIFT) >> Self::SHIFT)
+            }
+        }
+        impl TryFrom<$inner> for $name {
+            type Error = SerDesError;
+            #[inline]
+            fn try_from(v: $inner) -> crate::error::Result<Self> {
+                let v = $name(v);
+                v.check_width()?;
+                Ok(v)
+            }
+        }
+        impl From<$name> for $inner {
+            #[inline(always)]
+            fn from(v: $name) -> $inner {
+                v.0
+            }
+        }
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl $crate::utils::numerics::be_bytes::ToBeBytes<$len> for $name {
+            #[inline]
+            fn to_bytes(&self) -> [u8; $len] {
+                self.be_bytes()
+            }
+            #[inline]
+            fn check_width(&self) -> crate::error::Result<()> {
+                $name::check_width(self)
+            }
+        }
+        impl $crate::utils::numerics::le_bytes::ToLeBytes<$len> for $name {
+            #[inline]
+            fn to_bytes(&self) -> [u8; $len] {
+                self.le_bytes()
+            }
+            #[inline]
+            fn check_width(&self) -> crate::error::Result<()> {
+                $name::check_width(self)
+            }
+        }
+        impl $crate::utils::numerics::ne_bytes::ToNeBytes<$len> for $name {
+            #[inline]
+            fn to_bytes(&self) -> [u8; $len] {
+                match cfg!(target_endian = "big") {
+                    true => self.be_bytes(),
+                    false => self.le_bytes(),
+                }
+            }
+            #[inline]
+            fn check_width(&self) -> crate::error::Result<()> {
+                $name::check_width(self)
+            }
+        }
+        impl $crate::utils::numerics::be_bytes::FromBeBytes<$len, $name> for $name {
+            #[inline]
+            fn from_bytes(v: [u8; $len]) -> $name {
+                $name::from_be(&v)
+            }
+            #[inline]
+            fn from_bytes_ref(v: &[u8; $len]) -> $name {
+                $name::from_be(v)
+            }
+        }
+        impl $crate::utils::numerics::le_bytes::FromLeBytes<$len, $name> for $name {
+            #[inline]
+            fn from_bytes(v: [u8; $len]) -> $name {
+                $name::from_le(&v)
+            }
+            #[inline]
+            fn from_bytes_ref(v: &[u8; $len]) -> $name {
+                $name::from_le(v)
+            }
+        }
+        impl $crate::utils::numerics::ne_bytes::FromNeBytes<$len, $name> for $name {
+            #[inline]
+            fn from_bytes(v: [u8; $len]) -> $name {
+                Self::from_bytes_ref(&v)
+            }
+            #[inline]
+            fn from_bytes_ref(v: &[u8; $len]) -> $name {
+                match cfg!(target_endian = "big") {
+                    true => $name::from_be(v),
+                    false => $name::from_le(v),
+                }
+            }
+        }
+        impl ByteSerializedSizeOf for $name {
+            const BYTE_SIZE: usize = $len;
+            #[inline(always)]
+            fn byte_size() -> usize {
+                Self::BYTE_SIZE
+            }
+        }
+        impl ByteSerializedLenOf for $name {
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                Self::BYTE_SIZE
+            }
+        }
+    };
+}
+
+odd_width!(
+    /// Unsigned `3` byte numeric backed by `u32`
+    /// ```
+    /// use byteserde::prelude::*;
+    /// let mut ser = ByteSerializerStack::<8>::default();
+    /// ser.serialize_be(U24(0x010203)).unwrap();
+    /// assert_eq!(ser.as_slice(), &[0x01, 0x02, 0x03]);
+    /// assert!(ser.serialize_be(U24(0x01000000)).is_err());
+    /// ```
+    U24, u32, 3
+);
+odd_width!(
+    /// Signed `3` byte numeric backed by `i32`
+    /// ```
+    /// use byteserde::prelude::*;
+    /// let mut des = ByteDeserializerSlice::new(&[0xFF, 0xFF, 0xFE]);
+    /// let v: I24 = des.deserialize_be().unwrap();
+    /// assert_eq!(v, I24(-2));
+    /// ```
+    I24, i32, 3
+);
+odd_width!(
+    /// Unsigned `5` byte numeric backed by `u64`
+    U40, u64, 5
+);
+odd_width!(
+    /// Signed `5` byte numeric backed by `i64`
+    I40, i64, 5
+);
+odd_width!(
+    /// Unsigned `6` byte numeric backed by `u64`, ex: nanoseconds since midnight timestamp
+    U48, u64, 6
+);
+odd_width!(
+    /// Signed `6` byte numeric backed by `i64`
+    I48, i64, 6
+);
+odd_width!(
+    /// Unsigned `7` byte numeric backed by `u64`
+    U56, u64, 7
+);
+odd_width!(
+    /// Signed `7` byte numeric backed by `i64`
+    I56, i64, 7
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::numerics::{be_bytes::*, le_bytes::*, ne_bytes::*};
+
+    #[test]
+    fn test_range() {
+        assert_eq!(U24::MIN, 0);
+        assert_eq!(U24::MAX, 0x00FF_FFFF);
+        assert_eq!(I24::MIN, -0x0080_0000);
+        assert_eq!(I24::MAX, 0x007F_FFFF);
+        assert_eq!(U48::MAX, 0x0000_FFFF_FFFF_FFFF);
+        assert_eq!(I56::MIN, -0x0080_0000_0000_0000);
+        assert!(U24::try_from(0x0100_0000).is_err());
+        assert!(I24::try_from(-0x0080_0001).is_err());
+        assert!(ToBeBytes::check_width(&U40(u64::MAX)).is_err());
+        assert!(ToLeBytes::check_width(&I48(I48::MIN)).is_ok());
+    }
+    #[test]
+    fn test_sign_extend() {
+        let v = I24(-2);
+        assert_eq!(ToBeBytes::to_bytes(&v), [0xFF, 0xFF, 0xFE]);
+        assert_eq!(ToLeBytes::to_bytes(&v), [0xFE, 0xFF, 0xFF]);
+        assert_eq!(<I24 as FromBeBytes<3, I24>>::from_bytes([0xFF, 0xFF, 0xFE]), v);
+        assert_eq!(<I24 as FromLeBytes<3, I24>>::from_bytes([0xFE, 0xFF, 0xFF]), v);
+        assert_eq!(<I24 as FromNeBytes<3, I24>>::from_bytes(ToNeBytes::to_bytes(&v)), v);
+
+        let v = U48(0x0102_0304_0506);
+        assert_eq!(ToBeBytes::to_bytes(&v), [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(<U48 as FromLeBytes<6, U48>>::from_bytes(ToLeBytes::to_bytes(&v)), v);
+        assert_eq!(<I40 as FromBeBytes<5, I40>>::from_bytes([0x80, 0, 0, 0, 0]), I40(I40::MIN));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    des_bytes::{ByteDeserializeBytes, ByteDeserializerBytes},
+    des_slice::{ByteDeserializeSlice, ByteDeserializerSlice},
+    ser_heap::{ByteSerializeHeap, ByteSerializerHeap},
+    ser_stack::{ByteSerializeStack, ByteSerializerStack},
+};
+
+use super::odd_width::{I24, I40, I48, I56, U24, U40, U48, U56};
+
+/// calling
+///     impl_numeric_serde!(u16);
+/// will generate serialize & deserialize trait impls for `u16` so that it can be used with `ser.serialize(&1_u16)`,
+/// `des.deserialize::<u16>()` as well as inside of generic containers, arrays, tuples, `Vec`, etc.
+///
+/// # Appoach
+/// * `N` bytes of the numeric using endianess set on the serializer or deserializer via `set_endian()`, default is `native`
+/// * use [Be](super::wrappers::Be), [Le](super::wrappers::Le) or [Ne](super::wrappers::Ne) wrappers to fix the endianess in the type instead
+macro_rules! impl_numeric_serde {
+    ($($ty:ty),+) => {
+        $(
+        impl ByteSerializeStack for $ty {
+            #[inline(always)]
+            fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+                ser.serialize_endian(*self)?;
+                Ok(())
+            }
+        }
+        impl ByteSerializeHeap for $ty {
+            #[inline(always)]
+            fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+                ser.serialize_endian(*self)?;
+                Ok(())
+            }
+        }
+        impl ByteDeserializeSlice<$ty> for $ty {
+            #[inline(always)]
+            fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<$ty> {
+                des.deserialize_endian()
+            }
+        }
+        impl ByteDeserializeBytes<$ty> for $ty {
+            #[inline(always)]
+            fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<$ty> {
+                des.deserialize_endian()
+            }
+        }
+        )+
+    };
+}
+// `usize` & `isize` are excluded on purpose, their width depends on the target platform so the wire format would not be portable
+impl_numeric_serde!(i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+impl_numeric_serde!(U24, I24, U40, I40, U48, I48, U56, I56);
+
+// `u8` is endian agnostic and copies `[u8]` & `Vec<u8>` in one go instead of byte by byte
+impl ByteSerializeStack for u8 {
+    #[inline(always)]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(&[*self])?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn byte_serialize_stack_slice<const CAP: usize>(elements: &[u8], ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(elements)?;
+        Ok(())
+    }
+}
+impl ByteSerializeHeap for u8 {
+    #[inline(always)]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(&[*self])?;
+        Ok(())
+    }
+    #[inline(always)]
+    fn byte_serialize_heap_slice(elements: &[u8], ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(elements)?;
+        Ok(())
+    }
+}
+impl ByteDeserializeSlice<u8> for u8 {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<u8> {
+        des.deserialize_u8()
+    }
+    #[inline(always)]
+    fn byte_deserialize_remaining(des: &mut ByteDeserializerSlice) -> crate::error::Result<Vec<u8>> {
+        Ok(des.deserialize_bytes_slice_remaining().to_vec())
+    }
+}
+impl ByteDeserializeBytes<u8> for u8 {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<u8> {
+        des.deserialize_u8()
+    }
+    #[inline(always)]
+    fn byte_deserialize_remaining(des: &mut ByteDeserializerBytes) -> crate::error::Result<Vec<u8>> {
+        Ok(des.deserialize_bytes_slice_remaining().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_numerics() {
+        let mut ser = ByteSerializerHeap::default();
+        ser.set_endian(Endian::Big).serialize(&1_u16).unwrap().serialize(&-1_i8).unwrap().serialize(&U24(2)).unwrap();
+        assert_eq!(ser.as_slice(), &[0x00, 0x01, 0xFF, 0x00, 0x00, 0x02]);
+
+        let mut des = ByteDeserializerSlice::new(ser.as_slice());
+        des.set_endian(Endian::Big);
+        assert_eq!(des.deserialize::<u16>().unwrap(), 1);
+        assert_eq!(des.deserialize::<i8>().unwrap(), -1);
+        assert_eq!(des.deserialize::<U24>().unwrap(), U24(2));
+        assert!(des.deserialize::<u8>().is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    des_bytes::{ByteDeserializeBytes, ByteDeserializerBytes},
+    des_slice::{ByteDeserializeSlice, ByteDeserializerSlice},
+    ser_heap::{ByteSerializeHeap, ByteSerializerHeap},
+    ser_stack::{ByteSerializeStack, ByteSerializerStack},
+    size::{ByteSerializedLenOf, ByteSerializedSizeOf},
+};
+
+/// Numeric which is always serialized & deserialized using `big` endianess, irrespective of `#[byteserde(endian = ..)]`
+/// hence the endianess travels with the type through generics, arrays, `Vec` and `Option`.
+/// ```
+/// use byteserde::prelude::*;
+/// let ser: ByteSerializerStack<8> = to_serializer_stack(&Be(1_u16)).unwrap();
+/// assert_eq!(ser.as_slice(), &[0x00, 0x01]);
+/// let v: Be<u16> = from_slice(ser.as_slice()).unwrap();
+/// assert_eq!(*v, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Be<T>(pub T);
+
+/// Numeric which is always serialized & deserialized using `little` endianess, see [Be]
+/// ```
+/// use byteserde::prelude::*;
+/// let ser: ByteSerializerStack<8> = to_serializer_stack(&Le(1_u16)).unwrap();
+/// assert_eq!(ser.as_slice(), &[0x01, 0x00]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Le<T>(pub T);
+
+/// Numeric which is always serialized & deserialized using `native` endianess, see [Be]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Ne<T>(pub T);
+
+/// calling
+///     impl_wrapper!(Be);
+/// will generate `new`, `into_inner`, [From] & [Deref] impls for `Be<T>`
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<T> $wrapper<T> {
+            #[inline(always)]
+            pub fn new(v: T) -> Self {
+                $wrapper(v)
+            }
+            #[inline(always)]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+        impl<T> From<T> for $wrapper<T> {
+            #[inline(always)]
+            fn from(v: T) -> Self {
+                $wrapper(v)
+            }
+        }
+        impl<T> Deref for $wrapper<T> {
+            type Target = T;
+            #[inline(always)]
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+        impl<T> DerefMut for $wrapper<T> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    };
+}
+impl_wrapper!(Be);
+impl_wrapper!(Le);
+impl_wrapper!(Ne);
+
+/// calling
+///     impl_wrapper_serde!(Be, serialize_be, deserialize_be, u16);
+/// will generate serialize, deserialize, size & len trait impls for `Be<u16>`
+macro_rules! impl_wrapper_serde {
+    ($wrapper:ident, $ser:ident, $des:ident, $($ty:ty),+) => {
+        $(
+        impl ByteSerializeStack for $wrapper<$ty> {
+            #[inline(always)]
+            fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+                ser.$ser(self.0)?;
+                Ok(())
+            }
+        }
+        impl ByteSerializeHeap for $wrapper<$ty> {
+            #[inline(always)]
+            fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+                ser.$ser(self.0)?;
+                Ok(())
+            }
+        }
+        impl ByteDeserializeSlice<$wrapper<$ty>> for $wrapper<$ty> {
+            #[inline(always)]
+            fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<$wrapper<$ty>> {
+                Ok($wrapper(des.$des()?))
+            }
+        }
+        impl ByteDeserializeBytes<$wrapper<$ty>> for $wrapper<$ty> {
+            #[inline(always)]
+            fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<$wrapper<$ty>> {
+                Ok($wrapper(des.$des()?))
+            }
+        }
+        impl ByteSerializedSizeOf for $wrapper<$ty> {
+            const BYTE_SIZE: usize = std::mem::size_of::<$ty>();
+            #[inline(always)]
+            fn byte_size() -> usize {
+                Self::BYTE_SIZE
+            }
+        }
+        impl ByteSerializedLenOf for $wrapper<$ty> {
+            #[inline(always)]
+            fn byte_len(&self) -> usize {
+                Self::BYTE_SIZE
+            }
+        }
+        )+
+    };
+}
+impl_wrapper_serde!(Be, serialize_be, deserialize_be, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize);
+impl_wrapper_serde!(Le, serialize_le, deserialize_le, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize);
+impl_wrapper_serde!(Ne, serialize_ne, deserialize_ne, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize);
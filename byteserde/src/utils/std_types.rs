@@ -0,0 +1,324 @@
+//! Serialize, deserialize, size & len trait impls for `bool` and the std containers, so that they can be used directly with
+//! `ser.serialize(&[1_u16, 2])`, `des.deserialize::<(u8, u32)>()` as well as inside of generic `struct`s.
+//!
+//! # Wire convention
+//! * `bool` - `1` byte, `0x00` is `false` & `0x01` is `true`, any other value fails to deserialize
+//! * `[T; N]` - `N` elements of `T` back to back without a length prefix
+//! * `(A, B, ..)` - each element back to back in declaration order, up to `12` elements
+//! * `Vec<T>` - elements of `T` back to back without a length prefix, deserialization greedily depletes the remaining bytes,
+//!   use `deserialize_take()` or `#[byteserde(deplete( .. ))]` to limit it
+//! * `Vec<u8>` & `[u8; N]` - same as above but copied in one go rather than byte by byte
+//! * `Option<T>` - `Some` is serialized as `T` and `None` as nothing, deserialization yields `None` if there are no remaining bytes
+//! * `Box<T>` - same as `T`
+use crate::{
+    des_bytes::{ByteDeserializeBytes, ByteDeserializerBytes},
+    des_slice::{ByteDeserializeSlice, ByteDeserializerSlice},
+    error::{ErrorKind, SerDesError},
+    ser_heap::{ByteSerializeHeap, ByteSerializerHeap},
+    ser_stack::{ByteSerializeStack, ByteSerializerStack},
+    size::{ByteSerializedLenOf, ByteSerializedSizeOf},
+};
+
+// //////////////////////////////////////////////////////////////////////
+// bool
+impl ByteSerializeStack for bool {
+    #[inline(always)]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(&[*self as u8])?;
+        Ok(())
+    }
+}
+impl ByteSerializeHeap for bool {
+    #[inline(always)]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        ser.serialize_bytes_slice(&[*self as u8])?;
+        Ok(())
+    }
+}
+impl ByteDeserializeSlice<bool> for bool {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<bool> {
+        u8_2_bool(des.deserialize_u8()?)
+    }
+}
+impl ByteDeserializeBytes<bool> for bool {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<bool> {
+        u8_2_bool(des.deserialize_u8()?)
+    }
+}
+#[inline(always)]
+fn u8_2_bool(v: u8) -> crate::error::Result<bool> {
+    match v {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(SerDesError::with_kind(ErrorKind::UnexpectedValue, format!("bool expects 0x00 or 0x01 but got {v:#04x}"))),
+    }
+}
+
+// //////////////////////////////////////////////////////////////////////
+// [T; N]
+impl<T: ByteSerializeStack, const N: usize> ByteSerializeStack for [T; N] {
+    #[inline]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        T::byte_serialize_stack_slice(self, ser)
+    }
+}
+impl<T: ByteSerializeHeap, const N: usize> ByteSerializeHeap for [T; N] {
+    #[inline]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        T::byte_serialize_heap_slice(self, ser)
+    }
+}
+impl<T: ByteDeserializeSlice<T>, const N: usize> ByteDeserializeSlice<[T; N]> for [T; N] {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<[T; N]> {
+        try_array_from_fn(|_| des.deserialize())
+    }
+}
+impl<T: ByteDeserializeBytes<T>, const N: usize> ByteDeserializeBytes<[T; N]> for [T; N] {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<[T; N]> {
+        try_array_from_fn(|_| des.deserialize())
+    }
+}
+/// Builds `[T; N]` element by element calling `f` with the index of each element and stops at the first error, hence
+/// unlike `[T::default(); N]` it does not require `T` to be `Copy` or `Default` and does not allocate.
+/// Used by `byteserde_derive` to deserialize array members.
+/// ```
+/// use byteserde::utils::std_types::try_array_from_fn;
+/// let arr: [String; 2] = try_array_from_fn(|i| Ok(i.to_string())).unwrap();
+/// assert_eq!(arr, ["0".to_string(), "1".to_string()]);
+/// ```
+#[inline]
+pub fn try_array_from_fn<T, const N: usize>(mut f: impl FnMut(usize) -> crate::error::Result<T>) -> crate::error::Result<[T; N]> {
+    let mut err = None;
+    let elements: [Option<T>; N] = std::array::from_fn(|i| match err {
+        Some(_) => None,
+        None => match f(i) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                err = Some(e);
+                None
+            }
+        },
+    });
+    match err {
+        Some(e) => Err(e),
+        None => Ok(elements.map(|e| match e {
+            Some(v) => v,
+            None => unreachable!("all elements are set when there is no error"),
+        })),
+    }
+}
+impl<T: ByteSerializedSizeOf, const N: usize> ByteSerializedSizeOf for [T; N] {
+    const BYTE_SIZE: usize = T::BYTE_SIZE * N;
+    #[inline]
+    fn byte_size() -> usize {
+        T::byte_size() * N
+    }
+}
+impl<T: ByteSerializedLenOf, const N: usize> ByteSerializedLenOf for [T; N] {
+    #[inline]
+    fn byte_len(&self) -> usize {
+        self.iter().map(|e| e.byte_len()).sum()
+    }
+}
+
+// //////////////////////////////////////////////////////////////////////
+// (A, B, ..)
+
+/// calling
+///     impl_tuple!(A 0, B 1);
+/// will generate serialize, deserialize, size & len trait impls for `(A, B)`
+macro_rules! impl_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: ByteSerializeStack),+> ByteSerializeStack for ($($name,)+) {
+            #[inline]
+            fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+                $(self.$idx.byte_serialize_stack(ser)?;)+
+                Ok(())
+            }
+        }
+        impl<$($name: ByteSerializeHeap),+> ByteSerializeHeap for ($($name,)+) {
+            #[inline]
+            fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+                $(self.$idx.byte_serialize_heap(ser)?;)+
+                Ok(())
+            }
+        }
+        impl<$($name: ByteDeserializeSlice<$name>),+> ByteDeserializeSlice<($($name,)+)> for ($($name,)+) {
+            #[inline]
+            fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<($($name,)+)> {
+                Ok(($(des.deserialize::<$name>()?,)+))
+            }
+        }
+        impl<$($name: ByteDeserializeBytes<$name>),+> ByteDeserializeBytes<($($name,)+)> for ($($name,)+) {
+            #[inline]
+            fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<($($name,)+)> {
+                Ok(($(des.deserialize::<$name>()?,)+))
+            }
+        }
+        impl<$($name: ByteSerializedSizeOf),+> ByteSerializedSizeOf for ($($name,)+) {
+            const BYTE_SIZE: usize = 0 $(+ $name::BYTE_SIZE)+;
+            #[inline]
+            fn byte_size() -> usize {
+                0 $(+ $name::byte_size())+
+            }
+        }
+        impl<$($name: ByteSerializedLenOf),+> ByteSerializedLenOf for ($($name,)+) {
+            #[inline]
+            fn byte_len(&self) -> usize {
+                0 $(+ self.$idx.byte_len())+
+            }
+        }
+    };
+}
+impl_tuple!(A 0);
+impl_tuple!(A 0, B 1);
+impl_tuple!(A 0, B 1, C 2);
+impl_tuple!(A 0, B 1, C 2, D 3);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+// //////////////////////////////////////////////////////////////////////
+// Vec<T>
+impl<T: ByteSerializeStack> ByteSerializeStack for Vec<T> {
+    #[inline]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        T::byte_serialize_stack_slice(self, ser)
+    }
+}
+impl<T: ByteSerializeHeap> ByteSerializeHeap for Vec<T> {
+    #[inline]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        T::byte_serialize_heap_slice(self, ser)
+    }
+}
+/// Greedy deserialization of the remaining byte stream into a `Vec<T>`
+impl<T: ByteDeserializeSlice<T>> ByteDeserializeSlice<Vec<T>> for Vec<T> {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<Vec<T>> {
+        T::byte_deserialize_remaining(des)
+    }
+}
+/// Greedy deserialization of the remaining byte stream into a `Vec<T>`
+impl<T: ByteDeserializeBytes<T>> ByteDeserializeBytes<Vec<T>> for Vec<T> {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<Vec<T>> {
+        T::byte_deserialize_remaining(des)
+    }
+}
+#[cold]
+pub(crate) fn error_no_progress<T>() -> SerDesError {
+    SerDesError::new(format!("Vec<{}> element deserialization did not deplete any bytes", std::any::type_name::<T>()))
+}
+impl<T: ByteSerializedLenOf> ByteSerializedLenOf for Vec<T> {
+    #[inline]
+    fn byte_len(&self) -> usize {
+        self.iter().map(|e| e.byte_len()).sum()
+    }
+}
+
+// //////////////////////////////////////////////////////////////////////
+// Option<T>
+impl<T: ByteSerializeStack> ByteSerializeStack for Option<T> {
+    #[inline]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        match self {
+            Some(v) => v.byte_serialize_stack(ser),
+            None => Ok(()),
+        }
+    }
+}
+impl<T: ByteSerializeHeap> ByteSerializeHeap for Option<T> {
+    #[inline]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        match self {
+            Some(v) => v.byte_serialize_heap(ser),
+            None => Ok(()),
+        }
+    }
+}
+impl<T: ByteDeserializeSlice<T>> ByteDeserializeSlice<Option<T>> for Option<T> {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<Option<T>> {
+        match des.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(des.deserialize()?)),
+        }
+    }
+}
+impl<T: ByteDeserializeBytes<T>> ByteDeserializeBytes<Option<T>> for Option<T> {
+    #[inline]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<Option<T>> {
+        match des.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(des.deserialize()?)),
+        }
+    }
+}
+
+// //////////////////////////////////////////////////////////////////////
+// Box<T>
+impl<T: ByteSerializeStack> ByteSerializeStack for Box<T> {
+    #[inline(always)]
+    fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ByteSerializerStack<CAP>) -> crate::error::Result<()> {
+        self.as_ref().byte_serialize_stack(ser)
+    }
+}
+impl<T: ByteSerializeHeap> ByteSerializeHeap for Box<T> {
+    #[inline(always)]
+    fn byte_serialize_heap(&self, ser: &mut ByteSerializerHeap) -> crate::error::Result<()> {
+        self.as_ref().byte_serialize_heap(ser)
+    }
+}
+impl<T: ByteDeserializeSlice<T>> ByteDeserializeSlice<Box<T>> for Box<T> {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerSlice) -> crate::error::Result<Box<T>> {
+        Ok(Box::new(des.deserialize()?))
+    }
+}
+impl<T: ByteDeserializeBytes<T>> ByteDeserializeBytes<Box<T>> for Box<T> {
+    #[inline(always)]
+    fn byte_deserialize(des: &mut ByteDeserializerBytes) -> crate::error::Result<Box<T>> {
+        Ok(Box::new(des.deserialize()?))
+    }
+}
+impl<T: ByteSerializedSizeOf> ByteSerializedSizeOf for Box<T> {
+    const BYTE_SIZE: usize = T::BYTE_SIZE;
+    #[inline(always)]
+    fn byte_size() -> usize {
+        T::byte_size()
+    }
+}
+impl<T: ByteSerializedLenOf> ByteSerializedLenOf for Box<T> {
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.as_ref().byte_len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bool() {
+        let ser: ByteSerializerStack<4> = to_serializer_stack(&(true, false)).unwrap();
+        assert_eq!(ser.as_slice(), &[1, 0]);
+        assert_eq!(from_slice::<(bool, bool)>(ser.as_slice()).unwrap(), (true, false));
+        assert_eq!(from_slice::<bool>(&[2]).unwrap_err().kind(), ErrorKind::UnexpectedValue);
+    }
+    #[test]
+    fn test_vec_no_progress() {
+        assert_eq!(from_slice::<Vec<Option<u8>>>(&[1, 2]).unwrap(), vec![Some(1), Some(2)]);
+        assert!(from_slice::<Vec<[u8; 0]>>(&[1, 2]).is_err());
+    }
+}
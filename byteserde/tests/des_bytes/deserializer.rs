@@ -20,3 +20,56 @@ fn test_deserialser_array() {
     info!("res_err: {res_err:#?}");
     assert!(res_err.is_err());
 }
+
+#[test]
+fn test_deserialser_consumed_since() {
+    setup::log::configure();
+
+    let mut des = ByteDeserializerBytes::from(vec![1_u8, 2, 3, 4, 5]);
+    assert_eq!(des.consumed_since(0), &[]);
+    let start = des.idx();
+    des.deserialize_bytes_slice(3).unwrap();
+    info!("des: {des:#x}");
+    assert_eq!(des.consumed_since(start), &[1, 2, 3]);
+    assert_eq!(des.consumed_since(10), &[]);
+}
+
+#[test]
+fn test_deserialser_peek_until() {
+    setup::log::configure();
+
+    let mut des = ByteDeserializerBytes::from(b"abc\r\ndef".to_vec());
+    assert_eq!(des.peek_until(b"\r\n").unwrap(), 3);
+    assert_eq!(des.idx(), 0);
+    des.deserialize_bytes_slice(5).unwrap();
+    let err = des.peek_until(b"\r\n").unwrap_err();
+    info!("err: {err}");
+}
+
+#[test]
+fn test_deserialser_bounded() {
+    setup::log::configure();
+
+    let mut des = ByteDeserializerBytes::from(vec![1_u8, 2, 3, 4, 5]);
+    des.deserialize_u8().unwrap();
+    des.set_version(2);
+    let mut bounded = des.deserialize_bounded(3).unwrap();
+    assert_eq!(bounded.version(), Some(2));
+    info!("bounded: {bounded:#x}");
+    assert_eq!(des.idx(), 4);
+    assert_eq!(bounded.deserialize_bytes_slice_remaining(), &[2, 3, 4]);
+    assert!(bounded.deserialize_u8().is_err());
+    assert!(bounded.deserialize_i8().is_err());
+    assert!(des.deserialize_bounded(2).is_err());
+    assert_eq!(des.idx(), 4);
+}
+
+#[test]
+fn test_deserialser_take() {
+    setup::log::configure();
+
+    let mut des = ByteDeserializerBytes::from(b"abcdef".to_vec());
+    let out: bytes::Bytes = des.deserialize_take(2).unwrap();
+    assert_eq!(out, b"ab".as_slice());
+    assert_eq!(des.idx(), 2);
+}
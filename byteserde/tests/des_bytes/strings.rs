@@ -36,7 +36,7 @@ fn test_serialize_string_too_short_and_not_utf8() {
 
     //  create string shorter then its len indicates
     let ser = &mut ByteSerializerStack::<128>::default();
-    let _ = ser.serialize_bytes_slice(&8_usize.to_be_bytes());
+    let _ = ser.serialize_bytes_slice(&8_u32.to_be_bytes());
     let _ = ser.serialize_bytes_slice(&[0xFF_u8]);
     info!("ser: {ser:#x}");
     let mut des = ByteDeserializerBytes::new(ser.as_slice().to_vec().into());
@@ -46,7 +46,7 @@ fn test_serialize_string_too_short_and_not_utf8() {
 
     //  create invalid utf8
     let ser = &mut ByteSerializerStack::<128>::default();
-    let _ = ser.serialize_bytes_slice(&8_usize.to_be_bytes());
+    let _ = ser.serialize_bytes_slice(&8_u32.to_be_bytes());
     let _ = ser.serialize_bytes_slice(&[0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8]);
     info!("ser: {ser:#x}");
     let mut des = ByteDeserializerBytes::new(ser.as_slice().to_vec().into());
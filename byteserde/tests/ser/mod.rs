@@ -1,3 +1,4 @@
 mod numerics;
 mod serializer;
+mod std_types;
 mod strings;
@@ -32,3 +32,82 @@ fn test_serializer_u16() {
     assert(ser, u16::MIN, 4, false);
     assert(ser, 0x000B_u16, 6, false);
 }
+
+#[test]
+fn test_serializer_endian() {
+    setup::log::configure();
+    let mut ser_stack = ByteSerializerStack::<16>::default();
+    let mut ser_heap = ByteSerializerHeap::default();
+    assert_eq!(ser_stack.endian(), Endian::Native);
+    for (endian, expected) in [(Endian::Big, 0x0102_u16.to_be_bytes()), (Endian::Little, 0x0102_u16.to_le_bytes()), (Endian::Native, 0x0102_u16.to_ne_bytes())] {
+        ser_stack.clear();
+        ser_stack.set_endian(endian).serialize_endian(0x0102_u16).unwrap();
+        info!("endian: {endian:?}, ser_stack: {ser_stack:x}");
+        assert_eq!(ser_stack.as_slice(), expected);
+
+        ser_heap.clear();
+        ser_heap.set_endian(endian).serialize_endian(0x0102_u16).unwrap();
+        assert_eq!(ser_heap.as_slice(), expected);
+
+        let mut des = ByteDeserializerSlice::new(&expected);
+        let out: u16 = des.set_endian(endian).deserialize_endian().unwrap();
+        assert_eq!(out, 0x0102);
+        let mut des = ByteDeserializerBytes::new(expected.to_vec().into());
+        let out: u16 = des.set_endian(endian).deserialize_endian().unwrap();
+        assert_eq!(out, 0x0102);
+    }
+}
+
+#[test]
+fn test_serializer_endian_wrappers() {
+    setup::log::configure();
+    let mut ser_stack = ByteSerializerStack::<16>::default();
+    ser_stack.serialize(&Be(0x0102_u16)).unwrap().serialize(&Le(0x0102_u16)).unwrap().serialize(&Ne(0x0102_u16)).unwrap();
+    info!("ser_stack: {ser_stack:x}");
+    let mut ser_heap = ByteSerializerHeap::default();
+    ser_heap.serialize(&Be(0x0102_u16)).unwrap().serialize(&Le(0x0102_u16)).unwrap().serialize(&Ne(0x0102_u16)).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+    assert_eq!(&ser_stack.as_slice()[..4], &[0x01, 0x02, 0x02, 0x01]);
+    assert_eq!(&ser_stack.as_slice()[4..], &0x0102_u16.to_ne_bytes());
+
+    // runtime endian setting does not affect wrappers
+    let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+    des.set_endian(Endian::Little);
+    let (be, le, ne): (Be<u16>, Le<u16>, Ne<u16>) = (des.deserialize().unwrap(), des.deserialize().unwrap(), des.deserialize().unwrap());
+    assert_eq!((*be, *le, *ne), (0x0102, 0x0102, 0x0102));
+    let mut des = ByteDeserializerBytes::new(ser_heap.as_slice().to_vec().into());
+    let be: Be<u16> = des.deserialize().unwrap();
+    assert_eq!(be, Be(0x0102));
+
+    assert_eq!(Be::<u64>::BYTE_SIZE, 8);
+    assert_eq!(Le(1_u32).byte_len(), 4);
+}
+
+#[test]
+fn test_serializer_odd_width() {
+    setup::log::configure();
+    let mut ser_stack = ByteSerializerStack::<32>::default();
+    ser_stack.serialize_be(U24(0x010203)).unwrap().serialize_le(I24(-2)).unwrap().serialize_be(U56(1)).unwrap();
+    info!("ser_stack: {ser_stack:x}");
+    assert_eq!(ser_stack.as_slice(), &[0x01, 0x02, 0x03, 0xFE, 0xFF, 0xFF, 0, 0, 0, 0, 0, 0, 0x01]);
+    let mut ser_heap = ByteSerializerHeap::default();
+    ser_heap.serialize_be(U24(0x010203)).unwrap().serialize_le(I24(-2)).unwrap().serialize_be(U56(1)).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // out of range values are rejected by both serialize & patch
+    assert!(ser_stack.serialize_ne(U24(U24::MAX + 1)).is_err());
+    assert!(ser_heap.serialize_le(I40(I40::MAX + 1)).is_err());
+    let reserved = ser_heap.reserve::<U24>().unwrap();
+    assert!(ser_heap.patch_be(reserved, U24(u32::MAX)).is_err());
+    ser_heap.patch_be(reserved, U24(3)).unwrap();
+    assert_eq!(&ser_heap.as_slice()[13..], &[0, 0, 3]);
+
+    let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+    let (u24, i24, u56): (U24, I24, U56) = (des.deserialize_be().unwrap(), des.deserialize_le().unwrap(), des.deserialize_be().unwrap());
+    assert_eq!((u24, i24, u56), (U24(0x010203), I24(-2), U56(1)));
+    let mut des = ByteDeserializerBytes::new(ser_heap.as_slice().to_vec().into());
+    des.set_endian(Endian::Little);
+    des.deserialize_bytes_slice(3).unwrap();
+    let i24: I24 = des.deserialize_endian().unwrap();
+    assert_eq!(i24, I24(-2));
+}
@@ -75,3 +75,152 @@ fn test_serializer_stack_chained_impl() {
     assert_eq!(ser.len(), ser.capacity());
     assert_eq!(ser.len(), JUST_LONG_ENOUGH);
 }
+
+#[test]
+fn test_serializer_stack_checked() {
+    setup::log::configure();
+    struct Numerics {
+        a: u8,
+        b: u16,
+    }
+    impl ByteSerializeStack for Numerics {
+        fn byte_serialize_stack<const CAP: usize>(&self, serializer: &mut ByteSerializerStack<CAP>) -> byteserde::error::Result<()> {
+            serializer.serialize_bytes_slice(&[self.a])?.serialize_be(self.b)?;
+            Ok(())
+        }
+    }
+    impl ByteSerializedSizeOf for Numerics {
+        const BYTE_SIZE: usize = 3;
+        fn byte_size() -> usize {
+            Self::BYTE_SIZE
+        }
+    }
+    let x = Numerics { a: 1, b: 2 };
+    assert_eq!(Numerics::byte_size(), Numerics::BYTE_SIZE);
+
+    // implementations which only define byte_size() still work at run time but can't be checked at compile time
+    struct Legacy;
+    impl ByteSerializedSizeOf for Legacy {
+        fn byte_size() -> usize {
+            3
+        }
+    }
+    assert_eq!(<(Legacy, u8)>::byte_size(), 4);
+    assert_eq!(Legacy::BYTE_SIZE, usize::MAX);
+
+    // to_serializer_stack_checked::<2, Numerics>(&x) - would fail to compile
+    const JUST_LONG_ENOUGH: usize = 3;
+    let ser: ByteSerializerStack<JUST_LONG_ENOUGH> = to_serializer_stack_checked(&x).unwrap();
+    info!("ser {ser:#x}");
+    assert_eq!(ser.len(), JUST_LONG_ENOUGH);
+
+    let (bytes, len) = to_bytes_stack_checked::<128, Numerics>(&x).unwrap();
+    assert_eq!(&bytes[..len], ser.as_slice());
+}
+
+#[test]
+fn test_serializer_reserve_patch() {
+    setup::log::configure();
+    // stack
+    let mut ser = ByteSerializerStack::<16>::default();
+    ser.serialize_bytes_slice(&[0xFF]).unwrap();
+    let len_slot = ser.reserve::<u16>().unwrap();
+    ser.serialize_bytes_slice(&[1, 2, 3]).unwrap();
+    let len = ser.bytes_since(len_slot) as u16;
+    ser.patch_be(len_slot, len).unwrap();
+    info!("ser {ser:#x}");
+    assert_eq!(ser.as_slice(), &[0xFF, 0x00, 0x03, 1, 2, 3]);
+
+    ser.patch_ne(len_slot, 0x0102_u16).unwrap();
+    assert_eq!(ser.as_slice()[1..3], 0x0102_u16.to_ne_bytes());
+    assert_eq!(ser.len(), 6);
+
+    // heap
+    let mut ser_heap = ByteSerializerHeap::default();
+    ser_heap.serialize_bytes_slice(&[0xFF]).unwrap();
+    let len_slot = ser_heap.reserve::<u16>().unwrap();
+    ser_heap.serialize_bytes_slice(&[1, 2, 3]).unwrap();
+    let len = ser_heap.bytes_since(len_slot) as u16;
+    ser_heap.patch_be(len_slot, len).unwrap();
+    info!("ser_heap {ser_heap:#x}");
+    assert_eq!(ser_heap.as_slice(), &[0xFF, 0x00, 0x03, 1, 2, 3]);
+}
+
+#[test]
+fn test_serializer_reserve_patch_errors() {
+    setup::log::configure();
+    // not enough capacity to reserve
+    let mut ser = ByteSerializerStack::<3>::default();
+    ser.serialize_bytes_slice(&[0xFF]).unwrap();
+    let err = ser.reserve::<u32>().unwrap_err();
+    info!("err {err:#?}");
+
+    // slot reserved on a different serializer is out of range
+    let mut ser = ByteSerializerStack::<16>::default();
+    ser.serialize_bytes_slice(&[0; 8]).unwrap();
+    let slot = ser.reserve::<u64>().unwrap();
+    let mut other = ByteSerializerStack::<16>::default();
+    let err = other.patch_le(slot, 1_u64).unwrap_err();
+    info!("err {err:#?}");
+    let mut other = ByteSerializerHeap::default();
+    let err = other.patch_le(slot, 1_u64).unwrap_err();
+    info!("err {err:#?}");
+}
+
+#[test]
+fn test_serializer_patch_struct() {
+    setup::log::configure();
+    #[derive(Debug)]
+    struct Header {
+        a: u8,
+        b: u16,
+        size_lies: bool,
+    }
+    impl ByteSerializeStack for Header {
+        fn byte_serialize_stack<const CAP: usize>(&self, serializer: &mut ByteSerializerStack<CAP>) -> byteserde::error::Result<()> {
+            serializer.serialize_bytes_slice(&[self.a])?.serialize_be(self.b)?;
+            if self.size_lies {
+                serializer.serialize_bytes_slice(&[0xEE])?;
+            }
+            Ok(())
+        }
+    }
+    impl ByteSerializeHeap for Header {
+        fn byte_serialize_heap(&self, serializer: &mut ByteSerializerHeap) -> byteserde::error::Result<()> {
+            serializer.serialize_bytes_slice(&[self.a])?.serialize_be(self.b)?;
+            if self.size_lies {
+                serializer.serialize_bytes_slice(&[0xEE])?;
+            }
+            Ok(())
+        }
+    }
+    impl ByteSerializedSizeOf for Header {
+        fn byte_size() -> usize {
+            3
+        }
+    }
+
+    let mut ser = ByteSerializerStack::<16>::default();
+    let slot = ser.reserve::<Header>().unwrap();
+    ser.serialize_bytes_slice(&[9, 9]).unwrap();
+    assert_eq!(ser.as_slice(), &[0, 0, 0, 9, 9]);
+
+    ser.patch(slot, &Header { a: 1, b: 2, size_lies: false }).unwrap();
+    info!("ser {ser:#x}");
+    assert_eq!(ser.as_slice(), &[1, 0, 2, 9, 9]);
+
+    let err = ser.patch(slot, &Header { a: 1, b: 2, size_lies: true }).unwrap_err();
+    info!("err {err:#?}");
+    assert_eq!(ser.len(), 5);
+
+    let mut ser = ByteSerializerHeap::default();
+    let slot = ser.reserve::<Header>().unwrap();
+    ser.serialize_bytes_slice(&[9, 9]).unwrap();
+    ser.patch(slot, &Header { a: 1, b: 2, size_lies: false }).unwrap();
+    info!("ser {ser:#x}");
+    assert_eq!(ser.as_slice(), &[1, 0, 2, 9, 9]);
+
+    let err = ser.patch(slot, &Header { a: 3, b: 4, size_lies: true }).unwrap_err();
+    info!("err {err:#?}");
+    assert_eq!(ser.as_slice(), &[1, 0, 2, 9, 9]);
+}
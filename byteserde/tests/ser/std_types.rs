@@ -0,0 +1,81 @@
+use crate::integrationtest::setup;
+use byteserde::prelude::*;
+use log::info;
+
+#[test]
+fn test_serializer_std_types() {
+    setup::log::configure();
+    let inp = ([1_u16, 2], (3_u8, true, Be(4_u32)), vec![Le(5_u16)], Some(Box::new(6_i8)));
+
+    let mut ser_stack = ByteSerializerStack::<128>::default();
+    ser_stack.set_endian(Endian::Big).serialize(&inp).unwrap();
+    info!("ser_stack: {ser_stack:x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x00, 0x01, 0x00, 0x02,             // [u16; 2]
+        0x03, 0x01, 0x00, 0x00, 0x00, 0x04, // (u8, bool, Be<u32>)
+        0x05, 0x00,                         // Vec<Le<u16>>
+        0x06,                               // Option<Box<i8>>
+    ]);
+    let mut ser_heap = ByteSerializerHeap::default();
+    ser_heap.set_endian(Endian::Big).serialize(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // Vec is greedy hence only the last element may be a Vec or an Option
+    type Out = ([u16; 2], (u8, bool, Be<u32>), [Le<u16>; 1], Option<Box<i8>>);
+    let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+    let out: Out = des.set_endian(Endian::Big).deserialize().unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, (inp.0, inp.1, [inp.2[0]], inp.3.clone()));
+    let mut des = ByteDeserializerBytes::new(ser_heap.as_slice().to_vec().into());
+    let out: Out = des.set_endian(Endian::Big).deserialize().unwrap();
+    assert_eq!(out.3, Some(Box::new(6)));
+
+    // Option is None when there is nothing left & Vec takes the rest
+    let (arr, opt): ([u8; 2], Option<u8>) = from_slice(&[1, 2]).unwrap();
+    assert_eq!((arr, opt), ([1, 2], None));
+    let (head, rest): (u8, Vec<u8>) = from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!((head, rest), (1, vec![2, 3]));
+
+    assert_eq!(<([u16; 2], (u8, bool, f64), Box<u32>)>::BYTE_SIZE, 4 + 10 + 4);
+    assert_eq!(inp.byte_len(), 13);
+    // platform dependent numerics are not serializable but still report their size
+    assert_eq!(usize::byte_size(), std::mem::size_of::<usize>());
+    assert_eq!(1_isize.byte_len(), std::mem::size_of::<isize>());
+}
+
+#[test]
+fn test_deserializer_std_types_edge_cases() {
+    setup::log::configure();
+    // Option at the end of the input round trips both variants
+    let inp = (1_u8, Some(2_u16));
+    let ser = to_serializer_heap(&inp).unwrap();
+    assert_eq!(from_slice::<(u8, Option<u16>)>(ser.as_slice()).unwrap(), inp);
+    let inp = (1_u8, None::<u16>);
+    let ser = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser.as_slice(), &[1]);
+    assert_eq!(from_slice::<(u8, Option<u16>)>(ser.as_slice()).unwrap(), inp);
+    assert_eq!(from_bytes::<(u8, Option<u16>)>(ser.as_slice().to_vec().into()).unwrap(), inp);
+
+    // a partial trailing element is an error rather than None
+    let err = from_slice::<(u8, Option<u16>)>(&[1, 2]).unwrap_err();
+    info!("err: {err:#?}");
+
+    // Vec<u8> is copied in one go, deserialize_take limits it
+    let mut des = ByteDeserializerSlice::new(&[1, 2, 3]);
+    assert_eq!(des.deserialize_take::<Vec<u8>>(2).unwrap(), vec![1, 2]);
+    assert_eq!(des.deserialize::<Vec<u8>>().unwrap(), vec![3]);
+    let mut des = ByteDeserializerBytes::new(vec![1, 2, 3].into());
+    assert_eq!(des.deserialize_take::<Vec<u8>>(2).unwrap(), vec![1, 2]);
+    assert!(des.deserialize_take::<Vec<u8>>(2).is_err());
+    let ser: ByteSerializerStack<8> = to_serializer_stack(&(vec![1_u8, 2], [3_u8; 2])).unwrap();
+    assert_eq!(ser.as_slice(), &[1, 2, 3, 3]);
+
+    // Vec of elements which deplete no bytes must not loop forever
+    let err = from_slice::<Vec<[u8; 0]>>(&[1, 2]).unwrap_err();
+    info!("err: {err:#?}");
+    assert!(err.message.contains("did not deplete any bytes"));
+    let err = from_bytes::<Vec<[u16; 0]>>(vec![1, 2].into()).unwrap_err();
+    info!("err: {err:#?}");
+    assert!(err.message.contains("did not deplete any bytes"));
+}
@@ -8,14 +8,15 @@ use log::info;
 fn test_serialize_string() {
     setup::log::configure();
 
-    let size = size_of::<usize>();
+    let size = size_of::<u32>();
     let ser = &mut ByteSerializerStack::<128>::default();
 
     let inp = "whatever".to_string();
     let _ = inp.byte_serialize_stack(ser);
     info!("ser: {ser:#x}");
 
-    assert_eq!(8_usize.to_be_bytes(), ser.as_slice()[0..size]);
+    assert_eq!(8_u32.to_be_bytes(), ser.as_slice()[0..size]);
+    assert_eq!(inp.byte_len(), ser.len());
     assert_eq!(inp.len(), ser.as_slice()[size..].len());
 }
 
@@ -32,3 +33,39 @@ fn test_serialize_char() {
     assert_eq!([0x61_u8], ser.as_slice()[1..2]);
     assert_eq!(ser.len(), 2);
 }
+
+#[test]
+fn test_serialize_len_prefixed() {
+    setup::log::configure();
+    let inp = ("ab".to_string(), LenPrefixedString::<u16>::from("cd"), LenPrefixedString::<Le<u16>>::from("ef"), LenPrefixedBytes::<u8>::from(vec![1, 2]));
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0, 0, 0, 2, b'a', b'b', // String
+        0, 2, b'c', b'd',       // LenPrefixedString<u16>
+        2, 0, b'e', b'f',       // LenPrefixedString<Le<u16>>
+        2, 1, 2,                // LenPrefixedBytes<u8>
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+    assert_eq!(inp.byte_len(), ser_heap.len());
+
+    // prefix does not depend on the serializer endian setting
+    let mut ser_le = ByteSerializerHeap::default();
+    ser_le.set_endian(Endian::Little).serialize(&inp).unwrap();
+    assert_eq!(ser_le.as_slice(), ser_heap.as_slice());
+
+    type Out = (LenPrefixedString<u32>, LenPrefixedString<Be<u16>>, LenPrefixedString<Le<u16>>, LenPrefixedBytes<u8>);
+    let out: Out = from_slice(ser_stack.as_slice()).unwrap();
+    info!("out: {out:?}");
+    assert_eq!((out.0.as_str(), out.1.as_str(), out.2.as_str(), out.3.as_slice()), ("ab", "cd", "ef", &[1_u8, 2][..]));
+    let out: Out = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out.3.into_inner(), vec![1, 2]);
+
+    // length which does not fit into the prefix
+    let err = to_serializer_heap(&LenPrefixedString::<u8>::from("x".repeat(256))).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "length 256 does not fit into u8 length prefix");
+}
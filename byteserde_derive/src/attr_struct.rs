@@ -1,12 +1,13 @@
-use quote::ToTokens;
 use quote::__private::TokenStream;
+use quote::ToTokens;
 use quote::{__private::Span, quote};
 use syn::parse::Parse;
-use syn::{parenthesized, punctuated::Punctuated, token::Comma, Attribute, Expr, Ident, LitInt, LitStr, Member, Token};
+use syn::{parenthesized, punctuated::Punctuated, token::Comma, Attribute, Expr, ExprLit, Ident, Lit, LitBool, LitInt, LitStr, Member, Path, Token, Type, WherePredicate};
 pub fn ser_endian_method_xx(endian: &Endian) -> Ident {
     match endian {
         Endian::Big => Ident::new("serialize_be", Span::call_site()),
         Endian::Lit => Ident::new("serialize_le", Span::call_site()),
+        Endian::Runtime => Ident::new("serialize_endian", Span::call_site()),
         _ => Ident::new("serialize_ne", Span::call_site()),
     }
 }
@@ -14,6 +15,7 @@ pub fn des_endian_method_xx(endian: &Endian) -> Ident {
     match endian {
         Endian::Big => Ident::new("deserialize_be", Span::call_site()),
         Endian::Lit => Ident::new("deserialize_le", Span::call_site()),
+        Endian::Runtime => Ident::new("deserialize_endian", Span::call_site()),
         _ => Ident::new("deserialize_ne", Span::call_site()),
     }
 }
@@ -29,8 +31,7 @@ pub enum Deplete {
     Size(Expr),
 }
 pub fn deplete_attr(attrs: &[Attribute]) -> Deplete {
-    let (_, deplete, _, _, _, _, _) = get_attrs(attrs);
-    deplete
+    get_attrs(attrs).deplete
 }
 
 #[derive(Debug)]
@@ -39,8 +40,27 @@ pub enum Replace {
     Set(Expr),
 }
 pub fn replace_attr(attrs: &[Attribute]) -> Replace {
-    let (_, _, replace, _, _, _, _) = get_attrs(attrs);
-    replace
+    get_attrs(attrs).replace
+}
+
+pub enum Expect {
+    NotSet,
+    Set(Expr),
+}
+/// `expect( .. )` also implies `replace( .. )` during serialization, hence the two can't be combined
+pub fn expect_attr(fld_attrs: &[Attribute]) -> Expect {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.expect, attrs.replace) {
+        (Expect::Set(_), Replace::Set(_)) => panic!("#[byteserde(expect( .. ))] already implies #[byteserde(replace( .. ))] and can't be combined with it"),
+        (expect, _) => expect,
+    }
+}
+/// same as [replace_attr] but also yields `expect( .. )` expression since it is serialized the same way
+pub fn replace_or_expect_attr(fld_attrs: &[Attribute]) -> Replace {
+    match expect_attr(fld_attrs) {
+        Expect::Set(value) => Replace::Set(value),
+        Expect::NotSet => replace_attr(fld_attrs),
+    }
 }
 
 #[derive(Debug)]
@@ -48,17 +68,44 @@ pub enum Endian {
     Lit,
     Big,
     Native,
+    /// set on serializer & deserializer using `set_endian(..)`
+    Runtime,
     NotSet,
 }
 
+/// `#[byteserde(transparent)]` newtypes default to [Endian::Runtime] hence inherit explicit `endian` of the parent struct
 pub fn endian_attr(struct_attrs: &[Attribute], fld_attrs: &[Attribute]) -> Endian {
-    let (fld_endian, _, _, _, _, _, _) = get_attrs(fld_attrs);
-    match fld_endian {
-        Endian::NotSet => {
-            let (struct_endian, _, _, _, _, _, _) = get_attrs(struct_attrs);
-            struct_endian
-        }
-        _ => fld_endian,
+    match get_attrs(fld_attrs).endian {
+        Endian::NotSet => match get_attrs(struct_attrs) {
+            Attrs {
+                endian: Endian::NotSet,
+                transparent: true,
+                ..
+            } => Endian::Runtime,
+            struct_attrs => struct_attrs.endian,
+        },
+        fld_endian => fld_endian,
+    }
+}
+/// struct level, single member newtype serialized exactly as its member
+pub fn transparent_attr(struct_attrs: &[Attribute]) -> bool {
+    get_attrs(struct_attrs).transparent
+}
+/// member level `#[byteserde(as = "u8")]` numeric wire type the member is converted to & from
+pub fn as_attr(fld_attrs: &[Attribute]) -> Option<Type> {
+    let as_ty = get_attrs(fld_attrs).as_ty?;
+    match syn::parse_str::<Type>(&as_ty.value()) {
+        Ok(ty) => Some(ty),
+        Err(e) => panic!("#[byteserde(as = \"{}\")] is not a valid type: {e}", as_ty.value()),
+    }
+}
+
+/// struct level `#[byteserde(bound = "T: Trait, ..")]`, where predicates used instead of the inferred ones, `""` removes all inferred bounds
+pub fn bound_attr(struct_attrs: &[Attribute]) -> Option<Punctuated<WherePredicate, Comma>> {
+    let bound = get_attrs(struct_attrs).bound?;
+    match bound.parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated) {
+        Ok(predicates) => Some(predicates),
+        Err(e) => panic!("#[byteserde(bound = \"{}\")] is not a valid list of where predicates: {e}", bound.value()),
     }
 }
 
@@ -67,8 +114,7 @@ pub enum Peek {
     Set(Punctuated<LitInt, Comma>),
 }
 pub fn peek_attr(struct_attrs: &[Attribute]) -> Peek {
-    let (_, _, _, peek, _, _, _) = get_attrs(struct_attrs);
-    peek
+    get_attrs(struct_attrs).peek
 }
 
 pub enum PeekEq {
@@ -76,10 +122,10 @@ pub enum PeekEq {
     Set(Expr),
 }
 pub fn eq_attr(fld_attr: &[Attribute]) -> PeekEq {
-    let (_, _, _, _, eq, _, _) = get_attrs(fld_attr);
-    eq
+    get_attrs(fld_attr).eq
 }
 
+#[allow(dead_code)] // TODO might be used later for now disable warning
 pub enum Bind {
     NotSet,
     Set(Ident),
@@ -94,16 +140,324 @@ impl ToTokens for From {
     }
 }
 
-fn get_attrs(attrs: &[Attribute]) -> (Endian, Deplete, Replace, Peek, PeekEq, Bind, Vec<From>) {
+pub enum BackfillLen {
+    NotSet,
+    Of(Member),
+}
+pub fn backfill_len_attr(fld_attrs: &[Attribute]) -> BackfillLen {
+    get_attrs(fld_attrs).backfill_len
+}
+
+pub enum Checksum {
+    NotSet,
+    Set { algorithm: Ident, over: ChecksumOver },
+}
+pub enum ChecksumOver {
+    /// from the first byte of the struct up to the checksum member
+    Start,
+    /// from the first byte of the referenced member up to the checksum member
+    Member(Member),
+}
+pub fn checksum_attr(fld_attrs: &[Attribute]) -> Checksum {
+    let attrs = get_attrs(fld_attrs);
+    let algorithm = match attrs.checksum {
+        None => {
+            if attrs.over.is_some() {
+                panic!("#[byteserde(over = \"..\")] requires #[byteserde(checksum = \"..\")]");
+            }
+            return Checksum::NotSet;
+        }
+        Some(algorithm) => match algorithm.value().as_str() {
+            "crc32" => Ident::new("Crc32", Span::call_site()),
+            "crc16_ccitt" => Ident::new("Crc16Ccitt", Span::call_site()),
+            "fletcher16" => Ident::new("Fletcher16", Span::call_site()),
+            "mod256" => Ident::new("Mod256", Span::call_site()),
+            other => panic!("#[byteserde(checksum = \"{other}\")] is not supported, expected one of \"crc32\", \"crc16_ccitt\", \"fletcher16\", \"mod256\""),
+        },
+    };
+    let over = match attrs.over {
+        None => ChecksumOver::Start,
+        Some(over) => {
+            let value = over.value();
+            match value.split_once("..") {
+                Some(("start", "self")) => ChecksumOver::Start,
+                Some((from, "self")) => match syn::parse_str::<Member>(from.trim()) {
+                    Ok(member) => ChecksumOver::Member(member),
+                    Err(_) => panic!("#[byteserde(over = \"{value}\")] expected `start..self` or `member..self`"),
+                },
+                _ => panic!("#[byteserde(over = \"{value}\")] expected `start..self` or `member..self`"),
+            }
+        }
+    };
+    Checksum::Set { algorithm, over }
+}
+
+pub enum Skip {
+    NotSet,
+    /// `None` when the member shall be filled with `Default::default()` during deserialization
+    Set(Option<Expr>),
+}
+pub fn skip_attr(fld_attrs: &[Attribute]) -> Skip {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.skip, attrs.default) {
+        (true, default) => Skip::Set(default),
+        (false, Some(_)) => panic!("#[byteserde(default = ..)] requires #[byteserde(skip)]"),
+        (false, None) => Skip::NotSet,
+    }
+}
+
+pub fn with_attr(fld_attrs: &[Attribute]) -> Option<Path> {
+    get_attrs(fld_attrs).with
+}
+
+/// `N` of `#[byteserde(align = N)]`, padding inserted before the member so that it starts at a multiple of `N` bytes from the start of the struct
+pub fn align_attr(fld_attrs: &[Attribute]) -> Option<usize> {
+    match get_attrs(fld_attrs).align {
+        Some(0) => panic!("#[byteserde(align = 0)] must be greater than zero"),
+        align => align,
+    }
+}
+
+/// `C` of struct level `#[byteserde(ctx = C)]`, context type passed to `byte_serialize_xxx_with` & `byte_deserialize_with` and available as `ctx` in attribute expressions
+pub fn ctx_attr(struct_attrs: &[Attribute]) -> Option<Type> {
+    get_attrs(struct_attrs).ctx
+}
+
+/// member level `#[byteserde(since = N)]` and/or `#[byteserde(until_version = N)]` protocol version range. The upper bound is named
+/// `until_version` rather than `until` because `#[byteserde(until = ..)]` is the terminator of `Vec` & `String` like members.
+pub enum Version {
+    NotSet,
+    /// member is present when protocol version is in `since..=until` range, either bound is optional
+    Set {
+        since: Option<u32>,
+        until: Option<u32>,
+    },
+}
+pub fn version_attr(fld_attrs: &[Attribute]) -> Version {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.since, attrs.until_version) {
+        (None, None) => Version::NotSet,
+        (Some(since), Some(until)) if since > until => panic!("#[byteserde(since = {since})] must not be greater than #[byteserde(until_version = {until})]"),
+        (since, until) => Version::Set { since, until },
+    }
+}
+
+pub enum PadTo {
+    NotSet,
+    /// struct is padded with `fill` bytes up to `len` bytes
+    Set {
+        len: usize,
+        fill: u8,
+    },
+}
+pub fn pad_to_attr(struct_attrs: &[Attribute]) -> PadTo {
+    let attrs = get_attrs(struct_attrs);
+    match attrs.pad_to {
+        Some(0) => panic!("#[byteserde(pad_to = 0)] must be greater than zero"),
+        Some(len) => PadTo::Set { len, fill: attrs.fill.unwrap_or(0) },
+        None => PadTo::NotSet,
+    }
+}
+/// `fill` byte used for both `#[byteserde(pad_to = N)]` and `#[byteserde(align = N)]` padding, defaults to `0x00`
+pub fn fill_attr(struct_attrs: &[Attribute]) -> u8 {
+    get_attrs(struct_attrs).fill.unwrap_or(0)
+}
+
+pub enum VecLen {
+    NotSet,
+    /// number of elements
+    Count(Expr),
+    /// number of bytes occupied by all elements
+    ByteLen(Expr),
+}
+/// `#[byteserde(count( .. ))]` or `#[byteserde(byte_len( .. ))]`, which are mutually exclusive with each other and with `deplete( .. )`
+pub fn vec_len_attr(fld_attrs: &[Attribute]) -> VecLen {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.count, attrs.byte_len, attrs.deplete) {
+        (None, None, _) => VecLen::NotSet,
+        (Some(_), Some(_), _) => panic!("#[byteserde(count( .. ))] and #[byteserde(byte_len( .. ))] can't be combined"),
+        (_, _, Deplete::Size(_)) => panic!("#[byteserde(count( .. ))] and #[byteserde(byte_len( .. ))] can't be combined with #[byteserde(deplete( .. ))]"),
+        (Some(count), None, _) => VecLen::Count(count),
+        (None, Some(byte_len), _) => VecLen::ByteLen(byte_len),
+    }
+}
+
+pub enum Until {
+    NotSet,
+    /// `terminator` evaluates to `&[u8]`, `consume` is set when the terminator is written & consumed by the member
+    Set {
+        terminator: TokenStream,
+        consume: bool,
+    },
+}
+pub fn until_attr(fld_attrs: &[Attribute]) -> Until {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.until, attrs.consume_terminator) {
+        (
+            Some(Expr::Lit(ExprLit {
+                lit: lit @ (Lit::Int(_) | Lit::Byte(_)),
+                ..
+            })),
+            consume,
+        ) => Until::Set {
+            terminator: quote!( &[#lit] ),
+            consume: consume.unwrap_or(true),
+        },
+        (Some(expr), consume) => Until::Set {
+            terminator: quote!( ::std::convert::AsRef::<[u8]>::as_ref(&(#expr)) ),
+            consume: consume.unwrap_or(true),
+        },
+        (None, Some(_)) => panic!("#[byteserde(consume_terminator = ..)] requires #[byteserde(until = ..)]"),
+        (None, None) => Until::NotSet,
+    }
+}
+
+pub enum Presence {
+    NotSet,
+    /// `Option<T>` member is present in the byte stream only when `#[byteserde(when = expr)]` evaluates to `true`
+    When(Expr),
+    /// `Option<T>` member is preceded by a `u8` flag set to `1` when it is present and `0` otherwise
+    Flag,
+}
+pub fn presence_attr(fld_attrs: &[Attribute]) -> Presence {
+    let attrs = get_attrs(fld_attrs);
+    match (attrs.when, attrs.presence) {
+        (None, None) => Presence::NotSet,
+        (Some(when), None) => Presence::When(when),
+        (None, Some(presence)) => match presence.value().as_str() {
+            "flag" => Presence::Flag,
+            other => panic!("#[byteserde(presence = \"{other}\")] is not supported, expected \"flag\""),
+        },
+        (Some(_), Some(_)) => panic!("#[byteserde(when = ..)] and #[byteserde(presence = ..)] can't be combined"),
+    }
+}
+/// numeric type of the struct level `#[byteserde(presence_map = "u16")]` bitmap, one bit per `Option<T>` member
+pub fn presence_map_attr(struct_attrs: &[Attribute]) -> Option<Ident> {
+    let presence_map = get_attrs(struct_attrs).presence_map?;
+    match presence_map.value().as_str() {
+        "u8" | "u16" | "u32" | "u64" => Some(Ident::new(&presence_map.value(), presence_map.span())),
+        other => panic!("#[byteserde(presence_map = \"{other}\")] is not supported, expected one of \"u8\", \"u16\", \"u32\", \"u64\""),
+    }
+}
+
+/// numeric type of the `#[byteserde(count_prefix = "u16")]` entry count written ahead of map & set members
+pub fn count_prefix_attr(fld_attrs: &[Attribute]) -> Option<Ident> {
+    let count_prefix = get_attrs(fld_attrs).count_prefix?;
+    match count_prefix.value().as_str() {
+        "u8" | "u16" | "u32" | "u64" => Some(Ident::new(&count_prefix.value(), count_prefix.span())),
+        other => panic!("#[byteserde(count_prefix = \"{other}\")] is not supported, expected one of \"u8\", \"u16\", \"u32\", \"u64\""),
+    }
+}
+
+/// `#[byteserde(group(block_len = "u16", count = "u16"))]` numeric types of the repeating group header
+pub struct Group {
+    pub block_len: Ident,
+    pub count: Ident,
+}
+pub fn group_attr(fld_attrs: &[Attribute]) -> Option<Group> {
+    let (block_len, count) = get_attrs(fld_attrs).group?;
+    let numeric = |ty: LitStr, name: &str| match ty.value().as_str() {
+        "u8" | "u16" | "u32" | "u64" => Ident::new(&ty.value(), ty.span()),
+        other => panic!("#[byteserde(group({name} = \"{other}\"))] is not supported, expected one of \"u8\", \"u16\", \"u32\", \"u64\""),
+    };
+    Some(Group {
+        block_len: numeric(block_len, "block_len"),
+        count: numeric(count, "count"),
+    })
+}
+
+pub enum Validate {
+    NotSet,
+    /// `ser` is set when validation shall also run before serialization
+    Set {
+        path: Path,
+        ser: bool,
+    },
+}
+pub fn validate_attr(attrs: &[Attribute]) -> Validate {
+    let attrs = get_attrs(attrs);
+    match (attrs.validate, attrs.validate_ser) {
+        (Some(path), ser) => Validate::Set { path, ser: ser.unwrap_or(false) },
+        (None, Some(_)) => panic!("#[byteserde(validate_ser = ..)] requires #[byteserde(validate = path)]"),
+        (None, None) => Validate::NotSet,
+    }
+}
+
+#[allow(dead_code)] // TODO bind & from might be used later for now disable warning
+struct Attrs {
+    endian: Endian,
+    deplete: Deplete,
+    replace: Replace,
+    expect: Expect,
+    peek: Peek,
+    eq: PeekEq,
+    bind: Bind,
+    from: Vec<From>,
+    backfill_len: BackfillLen,
+    checksum: Option<LitStr>,
+    over: Option<LitStr>,
+    validate: Option<Path>,
+    validate_ser: Option<bool>,
+    skip: bool,
+    default: Option<Expr>,
+    with: Option<Path>,
+    align: Option<usize>,
+    pad_to: Option<usize>,
+    fill: Option<u8>,
+    until: Option<Expr>,
+    consume_terminator: Option<bool>,
+    count: Option<Expr>,
+    byte_len: Option<Expr>,
+    count_prefix: Option<LitStr>,
+    when: Option<Expr>,
+    presence: Option<LitStr>,
+    presence_map: Option<LitStr>,
+    group: Option<(LitStr, LitStr)>,
+    since: Option<u32>,
+    until_version: Option<u32>,
+    ctx: Option<Type>,
+    transparent: bool,
+    as_ty: Option<LitStr>,
+    bound: Option<LitStr>,
+}
+
+fn get_attrs(attrs: &[Attribute]) -> Attrs {
     let byteserde_attrs = attrs.iter().filter(|atr| atr.meta.path().is_ident("byteserde")).collect::<Vec<_>>();
 
     let mut endian = Endian::NotSet;
     let mut deplete = Deplete::NotSet;
     let mut replace = Replace::NotSet;
+    let mut expect = Expect::NotSet;
     let mut peek = Peek::NotSet;
     let mut eq = PeekEq::NotSet;
     let mut bind = Bind::NotSet;
     let mut from = Vec::<From>::new();
+    let mut backfill_len = BackfillLen::NotSet;
+    let mut checksum = None;
+    let mut over = None;
+    let mut validate = None;
+    let mut validate_ser = None;
+    let mut skip = false;
+    let mut default = None;
+    let mut with = None;
+    let mut align = None;
+    let mut pad_to = None;
+    let mut fill = None;
+    let mut until = None;
+    let mut consume_terminator = None;
+    let mut count = None;
+    let mut byte_len = None;
+    let mut count_prefix = None;
+    let mut when = None;
+    let mut presence = None;
+    let mut presence_map = None;
+    let mut group = None;
+    let mut since = None;
+    let mut until_version = None;
+    let mut ctx = None;
+    let mut transparent = false;
+    let mut as_ty = None;
+    let mut bound = None;
 
     // https://docs.rs/syn/latest/syn/meta/struct.ParseNestedMeta.html
 
@@ -119,8 +473,10 @@ fn get_attrs(attrs: &[Attribute]) -> (Endian, Deplete, Replace, Peek, PeekEq, Bi
                     endian = Endian::Lit;
                 } else if s.value() == "ne" {
                     endian = Endian::Native;
+                } else if s.value() == "runtime" {
+                    endian = Endian::Runtime;
                 } else {
-                    return Err(meta.error("Expected \"be\", \"le\", or \"ne\""));
+                    return Err(meta.error("Expected \"be\", \"le\", \"ne\" or \"runtime\""));
                 }
                 return Ok(());
             }
@@ -130,6 +486,13 @@ fn get_attrs(attrs: &[Attribute]) -> (Endian, Deplete, Replace, Peek, PeekEq, Bi
                 replace = Replace::Set(content.parse::<Expr>()?);
                 return Ok(());
             }
+            // numerics, arrays & structs, same as replace but also validated during deserialization
+            if meta.path.is_ident("expect") {
+                let content;
+                parenthesized!(content in meta.input);
+                expect = Expect::Set(content.parse::<Expr>()?);
+                return Ok(());
+            }
             // only affects variable length fields like String
             if meta.path.is_ident("deplete") {
                 let content;
@@ -167,13 +530,200 @@ fn get_attrs(attrs: &[Attribute]) -> (Endian, Deplete, Replace, Peek, PeekEq, Bi
                 from.push(From(content.parse::<Expr>()?));
                 return Ok(());
             }
+            // numerics only, length of an other member written during serialization
+            if meta.path.is_ident("backfill_len") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("of") {
+                        backfill_len = BackfillLen::Of(meta.value()?.parse::<Member>()?);
+                        return Ok(());
+                    }
+                    Err(meta.error("Expected `of = member`"))
+                })?;
+                return Ok(());
+            }
+            // numerics only, checksum computed over bytes preceding this member
+            if meta.path.is_ident("checksum") {
+                checksum = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("over") {
+                over = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            // struct & field level, fn(&T) -> Result<(), impl Display>
+            if meta.path.is_ident("validate") {
+                validate = Some(meta.value()?.parse::<Path>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("validate_ser") {
+                validate_ser = Some(meta.value()?.parse::<LitBool>()?.value());
+                return Ok(());
+            }
+            // member not present in the byte stream
+            if meta.path.is_ident("skip") {
+                skip = true;
+                return Ok(());
+            }
+            if meta.path.is_ident("default") {
+                default = Some(meta.value()?.parse::<Expr>()?);
+                return Ok(());
+            }
+            // custom codec module with serialize, deserialize & optional byte_len, byte_size fns
+            if meta.path.is_ident("with") {
+                with = Some(meta.value()?.parse::<Path>()?);
+                return Ok(());
+            }
+            // field level, padding before the member
+            if meta.path.is_ident("align") {
+                align = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+                return Ok(());
+            }
+            // struct level, padding after the last member
+            if meta.path.is_ident("pad_to") {
+                pad_to = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("fill") {
+                fill = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u8>()?);
+                return Ok(());
+            }
+            // Vec & String like members terminated by a sentinel, ex: 0x00, b'\n', b"\r\n"
+            if meta.path.is_ident("until") {
+                until = Some(meta.value()?.parse::<Expr>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("consume_terminator") {
+                consume_terminator = Some(meta.value()?.parse::<LitBool>()?.value());
+                return Ok(());
+            }
+            // Vec only, number of elements or number of bytes to deserialize
+            if meta.path.is_ident("count") {
+                let content;
+                parenthesized!(content in meta.input);
+                count = Some(content.parse::<Expr>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("byte_len") {
+                let content;
+                parenthesized!(content in meta.input);
+                byte_len = Some(content.parse::<Expr>()?);
+                return Ok(());
+            }
+            // map & set only, numeric type of the entry count written ahead of the entries
+            if meta.path.is_ident("count_prefix") {
+                count_prefix = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            // Option<T> only, condition referencing members deserialized before it
+            if meta.path.is_ident("when") {
+                when = Some(meta.value()?.parse::<Expr>()?);
+                return Ok(());
+            }
+            // Option<T> only, per member presence flag
+            if meta.path.is_ident("presence") {
+                presence = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            // struct level, presence bitmap of all Option<T> members
+            if meta.path.is_ident("presence_map") {
+                presence_map = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            // struct only, user context type
+            if meta.path.is_ident("ctx") {
+                ctx = Some(meta.value()?.parse::<Type>()?);
+                return Ok(());
+            }
+
+            // struct only, single member newtype
+            if meta.path.is_ident("transparent") {
+                transparent = true;
+                return Ok(());
+            }
+            // member only, numeric wire type converted using TryFrom
+            if meta.path.is_ident("as") {
+                as_ty = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+
+            // struct only, where predicates replacing inferred generic bounds
+            if meta.path.is_ident("bound") {
+                bound = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+
+            // member only, protocol version range, upper bound is not `until` which is taken by the terminator
+            if meta.path.is_ident("since") {
+                since = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u32>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("until_version") {
+                until_version = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u32>()?);
+                return Ok(());
+            }
+
+            // Vec only, repeating group header
+            if meta.path.is_ident("group") {
+                let (mut block_len, mut count) = (None, None);
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("block_len") {
+                        block_len = Some(meta.value()?.parse::<LitStr>()?);
+                        return Ok(());
+                    }
+                    if meta.path.is_ident("count") {
+                        count = Some(meta.value()?.parse::<LitStr>()?);
+                        return Ok(());
+                    }
+                    Err(meta.error("Expected `block_len = \"..\"` or `count = \"..\"`"))
+                })?;
+                match (block_len, count) {
+                    (Some(block_len), Some(count)) => group = Some((block_len, count)),
+                    _ => return Err(meta.error("Expected both `block_len = \"..\"` and `count = \"..\"`")),
+                }
+                return Ok(());
+            }
 
             Err(meta.error(format!("Unexpected attribute. {}", quote!(#attr))))
         });
-        if res.is_err() {
-            panic!("Failed to process attributes.\nattr: `{}`\n{}", attr.to_token_stream(), res.unwrap_err());
+        if let Err(e) = res {
+            panic!("Failed to process attributes.\nattr: `{}`\n{}", attr.to_token_stream(), e);
         }
     }
 
-    (endian, deplete, replace, peek, eq, bind, from)
+    Attrs {
+        endian,
+        deplete,
+        replace,
+        expect,
+        peek,
+        eq,
+        bind,
+        from,
+        backfill_len,
+        checksum,
+        over,
+        validate,
+        validate_ser,
+        skip,
+        default,
+        with,
+        align,
+        pad_to,
+        fill,
+        until,
+        consume_terminator,
+        count,
+        byte_len,
+        count_prefix,
+        when,
+        presence,
+        presence_map,
+        group,
+        since,
+        until_version,
+        ctx,
+        transparent,
+        as_ty,
+        bound,
+    }
 }
@@ -102,6 +102,16 @@ impl SerDesTokens {
             size_of
         }
     }
+    /// same as [Self::size_of()] but evaluated at run time, hence member types which only implement `fn byte_size()` are supported
+    pub fn byte_size_of(&self) -> Vec<TokenStream> {
+        self.size_of()
+            .iter()
+            .map(|size| {
+                let size = size.to_string().replace("ByteSerializedSizeOf > :: BYTE_SIZE", "ByteSerializedSizeOf > :: byte_size ()");
+                size.parse::<TokenStream>().unwrap()
+            })
+            .collect()
+    }
     pub fn size_errors(&self) -> Option<String> {
         let size_errors = self.flds.iter().map(|f| f.size_errors.clone()).collect::<Vec<_>>();
         collate_errors(size_errors)
@@ -1,10 +1,10 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
-use tokens_struct::{get_generics, get_struct_tokens};
+use tokens_struct::{get_generics, get_struct_tokens, struct_endian, validate_tokens};
 
 use crate::{
-    attr_struct::{peek_attr, Peek},
+    attr_struct::{ctx_attr, peek_attr, validate_attr, Peek, Validate},
     common::StructType,
 };
 // test only
@@ -21,21 +21,10 @@ pub fn byte_serialize_stack(input: TokenStream) -> TokenStream {
 
     // get struct name
     let struct_name = &ast.ident;
-    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast.generics);
-
-    // TODO add Own Derive traits to any generic bounds so that T: .... is not necessary
-    // #[derive( ByteSerializeStack, ByteDeserializeSlice, ByteSerializedLenOf, PartialEq, Serialize, Deserialize, Debug, Clone, Copy)]
-    // pub struct TagValueElement<T: ByteSerializeStack+ByteDeserializeSlice<T>+ByteSerializedLenOf>( // TODO T: should not have bounds if generated code adds them in byteserde
-    //     #[serde(skip)] u8,
-    //     #[serde(skip)] u8,
-    //     T,
-    // );
-
-    // let name =format!("{}", struct_name);
-    // if  name == "TagValueElement"{
-    //     println!("name: {}", name);
-    //     println!("generics_declaration: {:#?}", &ast.generics.params);
-    // }
+    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast, |_| match ctx_attr(&ast.attrs) {
+        Some(ctx) => quote!(::byteserde::prelude::ByteSerializeStackWith<#ctx>),
+        None => quote!(::byteserde::prelude::ByteSerializeStack),
+    });
 
     let res = get_struct_tokens(&ast);
     // grab just stack presets
@@ -47,24 +36,45 @@ pub fn byte_serialize_stack(input: TokenStream) -> TokenStream {
         StructType::Enum(_, _) => quote!( match self { #( #ser_uses_stck )* }),
         _ => quote!( #( #ser_uses_stck )* ),
     };
+    let ser_validate = ser_validate(&ast);
+    let ctx_unused = ctx_unused(&ast);
+    let ser_body = with_struct_endian(
+        &ast,
+        quote!(ser),
+        quote!(
+            #( #ser_vars )*
+            #( #ser_relp )*
+            #ser_uses_stck_expanded
+            Ok(())
+        ),
+    );
+    let (ser_trait, ser_fn) = match ctx_attr(&ast.attrs) {
+        Some(ctx) => (
+            quote!(::byteserde::prelude::ByteSerializeStackWith<#ctx>),
+            quote!(fn byte_serialize_stack_with<const CAP: usize>(&self, ser: &mut ::byteserde::prelude::ByteSerializerStack<CAP>, ctx: &#ctx) -> ::byteserde::error::Result<()>),
+        ),
+        None => (
+            quote!(::byteserde::prelude::ByteSerializeStack),
+            quote!(fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ::byteserde::prelude::ByteSerializerStack<CAP>) -> ::byteserde::error::Result<()>),
+        ),
+    };
 
     // generate stack serializer
     let output = quote! {
         #[automatically_derived]
-        impl #generics_declaration ::byteserde::prelude::ByteSerializeStack for #struct_name #generics_alias #where_clause{
+        impl #generics_declaration #ser_trait for #struct_name #generics_alias #where_clause{
         // impl byteserde::ser::ByteSerializeStack for #struct_name {
             #[inline]
-            fn byte_serialize_stack<const CAP: usize>(&self, ser: &mut ::byteserde::prelude::ByteSerializerStack<CAP>) -> ::byteserde::error::Result<()>{
+            #ser_fn {
                 // numerics
                 //      ser.serialize_[be|le|ne](self.field_name)?; -- for regular
                 //      ser.serialize_[be|le|ne](self.0         )?; -- for tuple
                 // trait ByteSerializeStack
                 //      self.field_name.byte_serialize_stack(ser)?;     -- for regular
                 //      self.0         .byte_serialize_stack(ser)?;     -- for tuple
-                #( #ser_vars )*
-                #( #ser_relp )*
-                #ser_uses_stck_expanded
-                Ok(())
+                #ctx_unused
+                #ser_validate
+                #ser_body
             }
         }
     };
@@ -76,7 +86,10 @@ pub fn byte_serialize_heap(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     // get struct name
     let struct_name = &ast.ident;
-    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast.generics);
+    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast, |_| match ctx_attr(&ast.attrs) {
+        Some(ctx) => quote!(::byteserde::prelude::ByteSerializeHeapWith<#ctx>),
+        None => quote!(::byteserde::prelude::ByteSerializeHeap),
+    });
     // get ser & des quote presets
     let res = get_struct_tokens(&ast);
     // grab just heap presets
@@ -88,44 +101,102 @@ pub fn byte_serialize_heap(input: TokenStream) -> TokenStream {
         StructType::Enum(_, _) => quote!( match self { #( #ser_uses_heap )* }),
         _ => quote!( #( #ser_uses_heap )* ),
     };
+    let ser_validate = ser_validate(&ast);
+    let ctx_unused = ctx_unused(&ast);
+    let ser_body = with_struct_endian(
+        &ast,
+        quote!(ser),
+        quote!(
+            #( #ser_vars )*
+            #( #ser_repl )*
+            #ser_uses_heap_expanded
+            Ok(())
+        ),
+    );
+    let (ser_trait, ser_fn) = match ctx_attr(&ast.attrs) {
+        Some(ctx) => (
+            quote!(::byteserde::prelude::ByteSerializeHeapWith<#ctx>),
+            quote!(fn byte_serialize_heap_with(&self, ser: &mut ::byteserde::prelude::ByteSerializerHeap, ctx: &#ctx) -> ::byteserde::error::Result<()>),
+        ),
+        None => (
+            quote!(::byteserde::prelude::ByteSerializeHeap),
+            quote!(fn byte_serialize_heap(&self, ser: &mut ::byteserde::prelude::ByteSerializerHeap) -> ::byteserde::error::Result<()>),
+        ),
+    };
 
     // generate heap serializer
     let output = quote! {
         #[automatically_derived]
-        impl #generics_declaration ::byteserde::prelude::ByteSerializeHeap for #struct_name #generics_alias #where_clause{
+        impl #generics_declaration #ser_trait for #struct_name #generics_alias #where_clause{
             #[inline]
-            fn byte_serialize_heap(&self, ser: &mut ::byteserde::prelude::ByteSerializerHeap) -> ::byteserde::error::Result<()>{
+            #ser_fn {
                 // numerics
                 //      ser.serialize_[be|le|ne](self.field_name)?;         -- for regular
                 //      ser.serialize_[be|le|ne](self.0         )?;         -- for tuple
                 // trait ByteSerializeStack
                 //      self.field_name.byte_serialize_heap(ser)?;          -- for regular
                 //      self.0         .byte_serialize_heap(ser)?;          -- for tuple
-                #( #ser_vars)*
-                #( #ser_repl)*
-                #ser_uses_heap_expanded
-                Ok(())
+                #ctx_unused
+                #ser_validate
+                #ser_body
             }
         }
     };
     output.into()
 }
 
+/// struct level `#[byteserde(ctx = ..)]` is in scope as `ctx` even when no member uses it
+fn ctx_unused(ast: &DeriveInput) -> quote::__private::TokenStream {
+    match ctx_attr(&ast.attrs) {
+        Some(_) => quote!( let _ = ctx; ),
+        None => quote!(),
+    }
+}
+
+/// struct level explicit `#[byteserde(endian = "be" | "le" | "ne")]` is set on `var` serializer or deserializer while `body` runs
+fn with_struct_endian(ast: &DeriveInput, var: quote::__private::TokenStream, body: quote::__private::TokenStream) -> quote::__private::TokenStream {
+    match struct_endian(ast) {
+        Some(endian) => quote!( #var.with_endian(#endian, |#var| { #body }) ),
+        None => body,
+    }
+}
+
+/// struct level `#[byteserde(validate = path, validate_ser = true)]` to be called before serialization
+fn ser_validate(ast: &DeriveInput) -> quote::__private::TokenStream {
+    match validate_attr(&ast.attrs) {
+        Validate::Set { path, ser: true } => validate_tokens(&ast.ident.to_string(), &path, &quote!(self)),
+        _ => quote!(),
+    }
+}
+
 #[proc_macro_derive(ByteDeserializeSlice, attributes(byteserde))]
 pub fn byte_deserialize_slice(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
-    byte_deserialize_common(ast, quote!(::byteserde::prelude::ByteDeserializerSlice), quote!(::byteserde::prelude::ByteDeserializeSlice))
+    byte_deserialize_common(
+        ast,
+        quote!(::byteserde::prelude::ByteDeserializerSlice),
+        quote!(::byteserde::prelude::ByteDeserializeSlice),
+        quote!(::byteserde::prelude::ByteDeserializeSliceWith),
+    )
 }
 
 #[proc_macro_derive(ByteDeserializeBytes, attributes(byteserde))]
 pub fn byte_deserialize_bytes(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
-    byte_deserialize_common(ast, quote!(::byteserde::prelude::ByteDeserializerBytes), quote!(::byteserde::prelude::ByteDeserializeBytes))
+    byte_deserialize_common(
+        ast,
+        quote!(::byteserde::prelude::ByteDeserializerBytes),
+        quote!(::byteserde::prelude::ByteDeserializeBytes),
+        quote!(::byteserde::prelude::ByteDeserializeBytesWith),
+    )
 }
 
-fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStream, _trait: quote::__private::TokenStream) -> TokenStream {
+fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStream, _trait: quote::__private::TokenStream, _trait_with: quote::__private::TokenStream) -> TokenStream {
     // get struct name
-    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast.generics);
+    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast, |param| match ctx_attr(&ast.attrs) {
+        Some(ctx) => quote!( #_trait_with<#param, #ctx> ),
+        None => quote!( #_trait<#param> ),
+    });
     // get ser & des quote presets
     let sdt = get_struct_tokens(&ast);
 
@@ -143,6 +214,19 @@ fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStr
         StructType::Unit(..) => quote!   ( Ok(#id) ),                   // NOTE nothing
         StructType::Enum(..) => quote!(),                               // NOTE nothing
     };
+    let impl_body = match (validate_attr(&ast.attrs), &sdt.struct_type) {
+        (Validate::NotSet, _) => impl_body,
+        (Validate::Set { .. }, StructType::Enum(..)) => panic!("{} #[byteserde(validate = ..)] is not supported on enums", ast.ident),
+        (Validate::Set { path, .. }, _) => {
+            let validate = validate_tokens(&ast.ident.to_string(), &path, &quote!(&__value));
+            quote!(
+                let __value: ::byteserde::error::Result<#id #generics_alias> = #impl_body;
+                let __value = __value?;
+                #validate
+                Ok(__value)
+            )
+        }
+    };
 
     let start_len = match peek {
         Peek::Set(v) => quote!(#v),
@@ -159,10 +243,7 @@ fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStr
                 let (start, len) = (#start_len);
                 let __peeked = peek(start, len)?;
                 #( #des_peeked )*
-                Err(::byteserde::prelude::SerDesError {
-                    message:
-                    format!("peek({}, {}) => {:x?}, however #[byteserde(eq( ... ))] did not yield a match. \ndes: {:#x}", start, len, __peeked, des),
-                })
+                Err(::byteserde::prelude::SerDesError::new(format!("peek({}, {}) => {:x?}, however #[byteserde(eq( ... ))] did not yield a match. \ndes: {:#x}", start, len, __peeked, des)))
             )
         }
         _ => match sdt.has_peeked_flds() {
@@ -179,12 +260,32 @@ fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStr
             false => quote!(),
         },
     };
+    let ctx_unused = ctx_unused(&ast);
+    let des_body = with_struct_endian(
+        &ast,
+        quote!(des),
+        quote!(
+            #( #des_vars )*
+            #des_peeked
+            #impl_body
+        ),
+    );
+    let (des_trait, des_fn) = match ctx_attr(&ast.attrs) {
+        Some(ctx) => (
+            quote!( #_trait_with<#id #generics_alias, #ctx> ),
+            quote!( fn byte_deserialize_with(des: &mut #_struct, ctx: &#ctx) -> ::byteserde::error::Result<#id #generics_alias> ),
+        ),
+        None => (
+            quote!( #_trait<#id #generics_alias> ),
+            quote!( fn byte_deserialize(des: &mut #_struct) -> ::byteserde::error::Result<#id #generics_alias> ),
+        ),
+    };
     // generate deserializer
     let output = quote!(
         #[automatically_derived]
-        impl #generics_declaration #_trait<#id #generics_alias> for #id #generics_alias #where_clause{
+        impl #generics_declaration #des_trait for #id #generics_alias #where_clause{
             #[inline]
-            fn byte_deserialize(des: &mut #_struct) -> ::byteserde::error::Result<#id #generics_alias>{
+            #des_fn {
                 // let type_u16:    u16 = des.deserialize_[be|le|ne]()?; -- numerics
                 // let type_String: String = des.deserialize()?;          -- trait ByteDeserializeSlice
                 // StructName { type_u16, type_String }
@@ -192,9 +293,8 @@ fn byte_deserialize_common(ast: DeriveInput, _struct: quote::__private::TokenStr
                 // let _0 = des.deserialize_[be|le|ne]()?; -- numerics
                 // let _1  = des.deserialize()?;          -- trait ByteDeserializeSlice
                 // TupleName ( _0, _1 )
-                #( #des_vars )*
-                #des_peeked
-                #impl_body
+                #ctx_unused
+                #des_body
             }
         }
     );
@@ -205,20 +305,22 @@ pub fn byte_serialized_size_of(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     // get struct name
     let struct_name = &ast.ident;
-    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast.generics);
+    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast, |_| quote!(::byteserde::size::ByteSerializedSizeOf));
     // get ser & des quote presets
     let res = get_struct_tokens(&ast);
     // grab just heap presets
     res.size_validate();
     let size = res.size_of();
+    let byte_size = res.byte_size_of();
 
     // generate deserializer
     let output = quote! {
         #[automatically_derived]
         impl #generics_declaration ::byteserde::size::ByteSerializedSizeOf for #struct_name #generics_alias #where_clause{
+            const BYTE_SIZE: usize = # ( #size )+*;
             #[inline]
-            fn byte_size() ->  usize{
-                # ( #size )+*
+            fn byte_size() -> usize {
+                # ( #byte_size )+*
             }
         }
     };
@@ -229,7 +331,7 @@ pub fn byte_serialized_len_of(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     // get struct name
     let struct_name = &ast.ident;
-    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast.generics);
+    let (generics_declaration, generics_alias, where_clause) = get_generics(&ast, |_| quote!(::byteserde::size::ByteSerializedLenOf));
     // get ser & des quote presets
     let res = get_struct_tokens(&ast);
     // grab just heap presets
@@ -5,19 +5,23 @@ use quote::{
     quote,
 };
 use syn::{
-    AngleBracketedGenericArguments, ConstParam, Data, DeriveInput, Expr, Field, Fields, GenericArgument, GenericParam, Generics, Ident, Index, Member, Path, PathArguments, Type, TypeArray, TypeGroup,
-    TypeParam, TypePath,
+    parse_quote, AngleBracketedGenericArguments, ConstParam, Data, DeriveInput, Expr, Field, Fields, GenericArgument, GenericParam, Ident, Index, Member, Path, PathArguments, Type, TypeArray,
+    TypeGroup, TypeParam, TypeParen, TypePath, TypeReference, TypeSlice, TypeTuple,
 };
 
 use crate::{
-    attr_struct::{deplete_attr, des_endian_method_xx, endian_attr, eq_attr, replace_attr, ser_endian_method_xx, Deplete, MemberIdent, PeekEq, Replace},
+    attr_struct::{
+        align_attr, as_attr, backfill_len_attr, bound_attr, checksum_attr, count_prefix_attr, ctx_attr, deplete_attr, des_endian_method_xx, endian_attr, eq_attr, expect_attr, fill_attr, group_attr,
+        pad_to_attr, peek_attr, presence_attr, presence_map_attr, replace_attr, replace_or_expect_attr, ser_endian_method_xx, skip_attr, transparent_attr, until_attr, validate_attr, vec_len_attr,
+        version_attr, with_attr, BackfillLen, Checksum, ChecksumOver, Deplete, Endian, Expect, Group, MemberIdent, PadTo, Peek, PeekEq, Presence, Replace, Skip, Until, Validate, VecLen, Version,
+    },
     common::{FldSerDesTokens, SerDesTokens, StructType},
 };
 
 pub fn get_struct_tokens(ast: &DeriveInput) -> SerDesTokens {
     let ty: StructType;
     let id = &ast.ident;
-    let flds_tokens = match &ast.data {
+    let mut flds_tokens = match &ast.data {
         Data::Struct(data) => match &data.fields {
             Fields::Named(flds) => {
                 ty = StructType::Regular(format!("{}", id), id.clone());
@@ -26,15 +30,38 @@ pub fn get_struct_tokens(ast: &DeriveInput) -> SerDesTokens {
                     .map(|fld| {
                         let var_name = fld.ident.as_ref().unwrap();
                         let member = &MemberIdent::Named(var_name);
+                        if let Some(tokens) = setup_skip(fld, var_name) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_with(fld, var_name, member) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_as(ast, fld, var_name, member) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_presence(ast, fld, var_name, member) {
+                            return tokens;
+                        }
                         let fld_type = map_field_type(&fld.ty);
+                        if let Some(tokens) = setup_until(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_group(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_map(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
                         match fld_type {
                             FieldType::Numeric { ty } | FieldType::Byte { ty, .. } => setup_numeric(ast, fld, ty, var_name, member, &fld_type),
-                            FieldType::ArrBytes { arr_ty, len, .. } | FieldType::ArrNumerics { arr_ty, len } | FieldType::ArrStructs { arr_ty, len } => {
+                            FieldType::ArrBytes { arr_ty, len } | FieldType::ArrNumerics { arr_ty, len } | FieldType::ArrStructs { arr_ty, len } | FieldType::ArrArrays { arr_ty, len } => {
                                 setup_array(ast, fld, arr_ty, var_name, len, member, &fld_type)
                             }
                             FieldType::VecBytes { .. } | FieldType::VecNumerics { .. } | FieldType::VecStructs { .. } => setup_vec(ast, fld, &fld.ty, var_name, member, &fld_type),
                             FieldType::OptionStructs { .. } => setup_option(ast, fld, &fld.ty, var_name, member, &fld_type),
-                            FieldType::Struct { ty } => setup_struct(fld, var_name, ty, member),
+                            FieldType::Struct { ty } => setup_struct(ast, fld, var_name, ty, member),
+                            FieldType::Composite { ty } => setup_composite(ast, fld, ty, var_name, member),
+                            FieldType::Map { .. } => unreachable!("map & set members are handled by setup_map"),
                         }
                     })
                     .collect::<Vec<_>>()
@@ -51,15 +78,38 @@ pub fn get_struct_tokens(ast: &DeriveInput) -> SerDesTokens {
                         });
                         let member = &MemberIdent::Unnamed(fld_index);
                         let var_name = &Ident::new(&format!("_{}", i), ast.ident.span());
+                        if let Some(tokens) = setup_skip(fld, var_name) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_with(fld, var_name, member) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_as(ast, fld, var_name, member) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_presence(ast, fld, var_name, member) {
+                            return tokens;
+                        }
                         let fld_type = map_field_type(&fld.ty);
+                        if let Some(tokens) = setup_until(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_group(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
+                        if let Some(tokens) = setup_map(ast, fld, var_name, member, &fld_type) {
+                            return tokens;
+                        }
                         match fld_type {
                             FieldType::Numeric { ty } | FieldType::Byte { ty, .. } => setup_numeric(ast, fld, ty, var_name, member, &fld_type),
-                            FieldType::ArrBytes { arr_ty, len, .. } | FieldType::ArrNumerics { arr_ty, len } | FieldType::ArrStructs { arr_ty, len } => {
+                            FieldType::ArrBytes { arr_ty, len } | FieldType::ArrNumerics { arr_ty, len } | FieldType::ArrStructs { arr_ty, len } | FieldType::ArrArrays { arr_ty, len } => {
                                 setup_array(ast, fld, arr_ty, var_name, len, member, &fld_type)
                             }
                             FieldType::VecBytes { .. } | FieldType::VecNumerics { .. } | FieldType::VecStructs { .. } => setup_vec(ast, fld, &fld.ty, var_name, member, &fld_type),
                             FieldType::OptionStructs { .. } => setup_option(ast, fld, &fld.ty, var_name, member, &fld_type),
-                            FieldType::Struct { ty } => setup_struct(fld, var_name, ty, member),
+                            FieldType::Struct { ty } => setup_struct(ast, fld, var_name, ty, member),
+                            FieldType::Composite { ty } => setup_composite(ast, fld, ty, var_name, member),
+                            FieldType::Map { .. } => unreachable!("map & set members are handled by setup_map"),
                         }
                     })
                     .collect::<Vec<_>>()
@@ -141,11 +191,1105 @@ pub fn get_struct_tokens(ast: &DeriveInput) -> SerDesTokens {
             )
         }
     };
+    validate_ctx(ast);
+    validate_transparent(ast);
+    setup_backfill_len(ast, &mut flds_tokens);
+    setup_checksum(ast, &mut flds_tokens);
+    setup_validate(ast, &mut flds_tokens);
+    setup_align(ast, &mut flds_tokens);
+    setup_version(ast, &mut flds_tokens);
+    setup_presence_map(ast, &mut flds_tokens);
+    setup_pad_to(ast, &mut flds_tokens);
     SerDesTokens { struct_type: ty, flds: flds_tokens }
 }
 
+/// struct level `#[byteserde(ctx = C)]` is in scope as `ctx` and is passed through to every nested struct like member or element, see [Nested]
+fn validate_ctx(ast: &DeriveInput) {
+    if !matches!(ast.data, Data::Struct(_)) && ctx_attr(&ast.attrs).is_some() {
+        panic!("{} #[byteserde(ctx = ..)] is only supported on structs", ast.ident);
+    }
+}
+/// struct level `#[byteserde(transparent)]` newtype is serialized exactly as its only member using `endian` inherited from the parent struct
+fn validate_transparent(ast: &DeriveInput) {
+    if !transparent_attr(&ast.attrs) {
+        return;
+    }
+    match &ast.data {
+        Data::Struct(data) if data.fields.len() == 1 => {}
+        _ => panic!("{} #[byteserde(transparent)] is only supported on structs with exactly one member", ast.ident),
+    }
+}
+/// wraps serialization of the member referenced by `#[byteserde(backfill_len(of = ..))]` so that its serialized length is patched into the reserved slot
+fn setup_backfill_len(ast: &DeriveInput, flds_tokens: &mut [FldSerDesTokens]) {
+    let flds = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return,
+    };
+    let struct_name = &ast.ident;
+    for (len_idx, len_fld) in flds.iter().enumerate() {
+        let of = match backfill_len_attr(&len_fld.attrs) {
+            BackfillLen::Of(of) => of,
+            BackfillLen::NotSet => continue,
+        };
+        let len_member = member_of(len_idx, len_fld);
+        let of_idx = match flds.iter().enumerate().position(|(i, f)| member_of(i, f) == of) {
+            Some(of_idx) if of_idx > len_idx => of_idx,
+            Some(_) => panic!(
+                "{struct_name}.{len} #[byteserde(backfill_len(of = {of} ))] must reference a member which follows it",
+                len = quote!(#len_member),
+                of = quote!(#of)
+            ),
+            None => panic!(
+                "{struct_name}.{len} #[byteserde(backfill_len(of = {of} ))] references a member which does not exist",
+                len = quote!(#len_member),
+                of = quote!(#of)
+            ),
+        };
+
+        // reserved position is recorded inside of the member's tokens which setup_version scopes to a block
+        if !matches!(version_attr(&len_fld.attrs), Version::NotSet) {
+            panic!(
+                "{struct_name}.{len} #[byteserde(backfill_len( .. ))] can't be combined with #[byteserde(since = ..)] or #[byteserde(until_version = ..)]",
+                len = quote!(#len_member)
+            );
+        }
+        // reserved slot is only recorded & patched for numeric members
+        let len_ty = &len_fld.ty;
+        if !matches!(map_field_type(len_ty), FieldType::Byte { .. } | FieldType::Numeric { .. }) {
+            panic!(
+                "{struct_name}.{len} #[byteserde(backfill_len( .. ))] member must be a numeric type, ex: u8, u16, u32, found: {ty}",
+                len = quote!(#len_member),
+                ty = quote!(#len_ty)
+            );
+        }
+        let reserved = backfill_len_reserved_ident(&len_member);
+        let start = Ident::new(&format!("__backfill_len_start_{}", quote!(#of)), Span::call_site());
+        let patch_xx = match endian_attr(&ast.attrs, &len_fld.attrs) {
+            Endian::Big => Ident::new("patch_be", Span::call_site()),
+            Endian::Lit => Ident::new("patch_le", Span::call_site()),
+            Endian::Runtime => Ident::new("patch_endian", Span::call_site()),
+            _ => Ident::new("patch_ne", Span::call_site()),
+        };
+        let overflow_error = format!(
+            "{struct_name}.{len} #[byteserde(backfill_len(of = {of} ))] length {{}} does not fit into {ty}",
+            len = quote!(#len_member),
+            of = quote!(#of),
+            ty = quote!(#len_ty),
+        );
+        let wrap = |ser_uses: &TokenStream| {
+            quote!(
+                let #start = ser.len();
+                #ser_uses
+                let __len = ser.len() - #start;
+                let __len = <#len_ty as ::std::convert::TryFrom<usize>>::try_from(__len)
+                    .map_err(|_| ::byteserde::prelude::SerDesError::new(format!(#overflow_error, __len)))?;
+                ser.#patch_xx(#reserved, __len)?;
+            )
+        };
+        let of_tokens = &mut flds_tokens[of_idx];
+        of_tokens.ser_uses_stck = wrap(&of_tokens.ser_uses_stck);
+        of_tokens.ser_uses_heap = wrap(&of_tokens.ser_uses_heap);
+    }
+}
+fn backfill_len_reserved_ident(member: &Member) -> Ident {
+    Ident::new(&format!("__backfill_len_reserved_{}", quote!(#member)), Span::call_site())
+}
+
+/// records serializer & deserializer position at the member where `#[byteserde(checksum = "..", over = "..")]` range starts
+fn setup_checksum(ast: &DeriveInput, flds_tokens: &mut [FldSerDesTokens]) {
+    let flds = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return,
+    };
+    let struct_name = &ast.ident;
+    for (cs_idx, cs_fld) in flds.iter().enumerate() {
+        let over = match checksum_attr(&cs_fld.attrs) {
+            Checksum::Set { over, .. } => over,
+            Checksum::NotSet => continue,
+        };
+        let cs_member = member_of(cs_idx, cs_fld);
+        // carried as u64 in ErrorKind::ChecksumMismatch
+        let cs_ty = &cs_fld.ty;
+        if !["u8", "u16", "u32", "u64"].iter().any(|unsigned| matches!(cs_ty, Type::Path(TypePath { path, .. }) if path.is_ident(unsigned))) {
+            panic!(
+                "{struct_name}.{cs} #[byteserde(checksum = \"..\")] member must be an unsigned numeric of the algorithm's width, ex: u8, u16, u32, u64, found: {ty}",
+                cs = quote!(#cs_member),
+                ty = quote!(#cs_ty)
+            );
+        }
+        let from_idx = match over {
+            ChecksumOver::Start if cs_idx > 0 => 0,
+            ChecksumOver::Start => panic!(
+                "{struct_name}.{cs} #[byteserde(checksum = \"..\", over = \"start..self\")] must follow at least one member",
+                cs = quote!(#cs_member)
+            ),
+            ChecksumOver::Member(from) => match flds.iter().enumerate().position(|(i, f)| member_of(i, f) == from) {
+                Some(from_idx) if from_idx < cs_idx => from_idx,
+                Some(_) => panic!(
+                    "{struct_name}.{cs} #[byteserde(over = \"{from}..self\")] must reference a member which precedes it",
+                    cs = quote!(#cs_member),
+                    from = quote!(#from)
+                ),
+                None => panic!(
+                    "{struct_name}.{cs} #[byteserde(over = \"{from}..self\")] references a member which does not exist",
+                    cs = quote!(#cs_member),
+                    from = quote!(#from)
+                ),
+            },
+        };
+
+        // start position is recorded inside of the member's tokens which setup_version scopes to a block
+        let from_fld = flds.iter().nth(from_idx).unwrap();
+        if !matches!(version_attr(&from_fld.attrs), Version::NotSet) {
+            let from_member = member_of(from_idx, from_fld);
+            panic!(
+                "{struct_name}.{cs} #[byteserde(checksum = \"..\", over = \"..\")] range can't start at member {from} gated by #[byteserde(since = ..)] or #[byteserde(until_version = ..)]",
+                cs = quote!(#cs_member),
+                from = quote!(#from_member)
+            );
+        }
+        let start = checksum_start_ident(&cs_member);
+        let from_tokens = &mut flds_tokens[from_idx];
+        let (ser_uses_stck, ser_uses_heap, des_vars) = (&from_tokens.ser_uses_stck, &from_tokens.ser_uses_heap, &from_tokens.des_vars);
+        from_tokens.ser_uses_stck = quote!( let #start = ser.len(); #ser_uses_stck );
+        from_tokens.ser_uses_heap = quote!( let #start = ser.len(); #ser_uses_heap );
+        from_tokens.des_vars = quote!( let #start = des.idx(); #des_vars );
+    }
+}
+/// calls `#[byteserde(validate = path)]` on each member right after it is deserialized and optionally before the struct is serialized
+fn setup_validate(ast: &DeriveInput, flds_tokens: &mut [FldSerDesTokens]) {
+    let flds = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return,
+    };
+    for (idx, fld) in flds.iter().enumerate() {
+        let (path, ser) = match validate_attr(&fld.attrs) {
+            Validate::Set { path, ser } => (path, ser),
+            Validate::NotSet => continue,
+        };
+        let member = member_of(idx, fld);
+        if let FieldType::OptionStructs { .. } = map_field_type(&fld.ty) {
+            panic!(
+                "{}.{} #[byteserde(validate = ..)] is not supported on Option members, validate the struct instead",
+                ast.ident,
+                quote!(#member)
+            );
+        }
+        let var_name = match &member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => Ident::new(&format!("_{}", index.index), ast.ident.span()),
+        };
+        let label = format!("{}.{}", ast.ident, quote!(#member));
+
+        let fld_tokens = &mut flds_tokens[idx];
+        let des_vars = &fld_tokens.des_vars;
+        let des_validate = validate_tokens(&label, &path, &quote!(&#var_name));
+        fld_tokens.des_vars = quote!( #des_vars #des_validate );
+        if ser {
+            let (ser_uses_stck, ser_uses_heap) = (&fld_tokens.ser_uses_stck, &fld_tokens.ser_uses_heap);
+            let ser_validate = validate_tokens(&label, &path, &quote!(&self.#member));
+            fld_tokens.ser_uses_stck = quote!( #ser_validate #ser_uses_stck );
+            fld_tokens.ser_uses_heap = quote!( #ser_validate #ser_uses_heap );
+        }
+    }
+}
+/// calls `path(subject)` and wraps its error into [SerDesError] prefixed with the `label`
+pub fn validate_tokens(label: &str, path: &Path, subject: &TokenStream) -> TokenStream {
+    let error = format!("{label} #[byteserde(validate = {})] failed: {{}}", quote!(#path).to_string().replace(' ', ""));
+    quote!(
+        #path(#subject).map_err(|e| ::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::ValidationFailed, format!(#error, e)))?;
+    )
+}
+/// inserts `#[byteserde(fill = ..)]` bytes before each member annotated with `#[byteserde(align = N)]` so that it starts at a multiple of `N` bytes from the start of the struct,
+/// padding is skipped during deserialization and included in the size & length
+fn setup_align(ast: &DeriveInput, flds_tokens: &mut [FldSerDesTokens]) {
+    let flds = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return,
+    };
+    let fill = fill_attr(&ast.attrs);
+    for (idx, fld) in flds.iter().enumerate() {
+        let align = match align_attr(&fld.attrs) {
+            Some(align) => align,
+            None => continue,
+        };
+        let member = member_of(idx, fld);
+        if let FieldType::OptionStructs { .. } = map_field_type(&fld.ty) {
+            panic!("{}.{} #[byteserde(align = {align})] is not supported on Option members", ast.ident, quote!(#member));
+        }
+        let start = struct_start_ident();
+        // presence map is inserted ahead of all members later hence is accounted for here
+        let presence_map = presence_map_attr(&ast.attrs).map(|map_ty| quote!( ::std::mem::size_of::<#map_ty>() ));
+        let prev_size_of = presence_map
+            .iter()
+            .cloned()
+            .chain(flds_tokens[..idx].iter().filter(|f| !f.size_of.is_empty()).map(|f| f.size_of.clone()))
+            .collect::<Vec<_>>();
+        let prev_len_of = presence_map
+            .iter()
+            .cloned()
+            .chain(flds_tokens[..idx].iter().filter(|f| !f.len_of.is_empty()).map(|f| f.len_of.clone()))
+            .collect::<Vec<_>>();
+
+        let fld_tokens = &mut flds_tokens[idx];
+        let ser_pad = quote!(
+            let __pad = (#align - (ser.len() - #start) % #align) % #align;
+            ser.serialize_bytes_slice(&[#fill; #align][..__pad])?;
+        );
+        let (ser_uses_stck, ser_uses_heap, des_vars) = (&fld_tokens.ser_uses_stck, &fld_tokens.ser_uses_heap, &fld_tokens.des_vars);
+        fld_tokens.ser_uses_stck = quote!( #ser_pad #ser_uses_stck );
+        fld_tokens.ser_uses_heap = quote!( #ser_pad #ser_uses_heap );
+        fld_tokens.des_vars = quote!(
+            let __pad = (#align - (des.idx() - #start) % #align) % #align;
+            des.deserialize_bytes_slice(__pad)?;
+            #des_vars
+        );
+        if !prev_size_of.is_empty() && !fld_tokens.size_of.is_empty() {
+            let size_of = &fld_tokens.size_of;
+            fld_tokens.size_of = quote!( ((#align - ( #( #prev_size_of )+* ) % #align) % #align + #size_of) );
+        }
+        if !prev_len_of.is_empty() && !fld_tokens.len_of.is_empty() {
+            let len_of = &fld_tokens.len_of;
+            fld_tokens.len_of = quote!( ((#align - ( #( #prev_len_of )+* ) % #align) % #align + #len_of) );
+        }
+    }
+}
+/// records serializer & deserializer position at the start of the struct when any member is aligned and appends `#[byteserde(pad_to = N, fill = ..)]` padding after the last member
+fn setup_pad_to(ast: &DeriveInput, flds_tokens: &mut Vec<FldSerDesTokens>) {
+    let struct_name = &ast.ident;
+    let pad_to = pad_to_attr(&ast.attrs);
+    let flds = match (&ast.data, &pad_to) {
+        (Data::Struct(data), _) => &data.fields,
+        (_, PadTo::Set { .. }) => panic!("{struct_name} #[byteserde(pad_to = ..)] is not supported on enums"),
+        (_, PadTo::NotSet) => return,
+    };
+    let aligned = flds.iter().any(|fld| align_attr(&fld.attrs).is_some());
+    if !aligned && matches!(pad_to, PadTo::NotSet) {
+        return;
+    }
+    let empty = FldSerDesTokens {
+        ser_vars: quote!(),
+        ser_repl: quote!(),
+        ser_uses_stck: quote!(),
+        ser_uses_heap: quote!(),
+        des_vars: quote!(),
+        des_peeked: quote!(),
+        des_uses: quote!(),
+        des_errors: vec![],
+        size_of: quote!(),
+        size_errors: vec![],
+        len_of: quote!(),
+    };
+
+    let (len, fill) = match pad_to {
+        PadTo::Set { len, fill } => (len, fill),
+        PadTo::NotSet => (0, 0),
+    };
+    let pad_tokens = match pad_to {
+        PadTo::Set { .. } => {
+            if let Peek::Set(_) = peek_attr(&ast.attrs) {
+                panic!("{struct_name} #[byteserde(pad_to = {len})] can't be combined with #[byteserde(peek( .. ))]");
+            }
+            let start = struct_start_ident();
+            let size_of = flds_tokens.iter().filter(|f| !f.size_of.is_empty()).map(|f| f.size_of.clone()).collect::<Vec<_>>();
+            let len_of = flds_tokens.iter().filter(|f| !f.len_of.is_empty()).map(|f| f.len_of.clone()).collect::<Vec<_>>();
+            let (size_of, len_of) = match size_of.is_empty() {
+                true => (quote!(0), quote!(0)),
+                false => (quote!( #( #size_of )+* ), quote!( #( #len_of )+* )),
+            };
+            let ser_error = format!("{struct_name} #[byteserde(pad_to = {len})] serialized length {{}} exceeds {len} bytes");
+            let des_error = format!("{struct_name} #[byteserde(pad_to = {len})] deserialized length {{}} exceeds {len} bytes");
+            let size_error = format!("{struct_name} #[byteserde(pad_to = {len})] members do not fit into {len} bytes");
+            let ser_pad = quote!(
+                let __len = ser.len() - #start;
+                if __len > #len {
+                    return Err(::byteserde::prelude::SerDesError::new(format!(#ser_error, __len)));
+                }
+                ser.serialize_bytes_slice(&[#fill; #len][..#len - __len])?;
+            );
+            Some(FldSerDesTokens {
+                ser_uses_stck: ser_pad.clone(),
+                ser_uses_heap: ser_pad,
+                des_vars: quote!(
+                    let __len = des.idx() - #start;
+                    if __len > #len {
+                        return Err(::byteserde::prelude::SerDesError::new(format!(#des_error, __len)));
+                    }
+                    des.deserialize_bytes_slice(#len - __len)?;
+                ),
+                size_of: quote!({
+                    let __size = #size_of;
+                    if __size > #len {
+                        panic!(#size_error);
+                    }
+                    #len - __size
+                }),
+                len_of: quote!( #len.saturating_sub(#len_of) ),
+                ..empty.clone()
+            })
+        }
+        PadTo::NotSet => None,
+    };
+
+    let start = struct_start_ident();
+    flds_tokens.insert(
+        0,
+        FldSerDesTokens {
+            ser_uses_stck: quote!( let #start = ser.len(); ),
+            ser_uses_heap: quote!( let #start = ser.len(); ),
+            des_vars: quote!( let #start = des.idx(); ),
+            ..empty
+        },
+    );
+    if let Some(pad_tokens) = pad_tokens {
+        flds_tokens.push(pad_tokens);
+    }
+}
+fn struct_start_ident() -> Ident {
+    Ident::new("__struct_start", Span::call_site())
+}
+fn checksum_start_ident(member: &Member) -> Ident {
+    Ident::new(&format!("__checksum_start_{}", quote!(#member)), Span::call_site())
+}
+fn member_of(idx: usize, fld: &Field) -> Member {
+    match &fld.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(idx)),
+    }
+}
+
+/// members annotated with `#[byteserde(skip)]` or of `PhantomData` type are not present in the byte stream and are created using `Default::default()` or `#[byteserde(default = ..)]` expression during deserialization
+fn setup_skip(fld: &Field, var_name: &Ident) -> Option<FldSerDesTokens> {
+    let ty = &fld.ty;
+    let default = match (skip_attr(&fld.attrs), is_phantom_data(ty)) {
+        (Skip::Set(Some(default)), _) => quote!( #default ),
+        (Skip::Set(None), _) | (Skip::NotSet, true) => quote!(::std::default::Default::default()),
+        (Skip::NotSet, false) => return None,
+    };
+    Some(FldSerDesTokens {
+        ser_vars: quote!(),
+        ser_repl: quote!(),
+        ser_uses_stck: quote!(),
+        ser_uses_heap: quote!(),
+        des_vars: quote!( let #var_name: #ty = #default; ),
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!(),
+        size_errors: vec![],
+        len_of: quote!(),
+    })
+}
+/// members annotated with `#[byteserde(with = module)]` are serialized using `module::serialize`, `module::deserialize`, `module::byte_size` & `module::byte_len` fns
+fn setup_with(fld: &Field, var_name: &Ident, member: &MemberIdent) -> Option<FldSerDesTokens> {
+    let with = with_attr(&fld.attrs)?;
+    let ty = &fld.ty;
+    let member = match member {
+        MemberIdent::Named(fld_name) => quote!( self.#fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( self.#fld_index ),
+    };
+    let ser_repl = match replace_attr(&fld.attrs) {
+        Replace::Set(value) => quote!( let #var_name: &#ty = &#value; ),
+        Replace::NotSet => quote!(),
+    };
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &#member; ),
+        ser_repl,
+        ser_uses_stck: quote!( #with::serialize(#var_name, ser)?; ),
+        ser_uses_heap: quote!( #with::serialize(#var_name, ser)?; ),
+        des_vars: quote!( let #var_name: #ty = #with::deserialize(des)?; ),
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!( #with::byte_size() ),
+        size_errors: vec![],
+        len_of: quote!( #with::byte_len(&#member) ),
+    })
+}
+/// `Vec` & `String` like members terminated by `#[byteserde(until = ..)]` sentinel instead of a length, the serialized payload must not contain the terminator.
+/// Bytes & strings are searched for the terminator byte by byte, while `Vec<T>` of wider elements only match it at element boundaries.
+/// When `#[byteserde(consume_terminator = false)]` the terminator is neither written nor consumed and is expected to be handled by the member which follows.
+fn setup_until(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent, fld_type: &FieldType) -> Option<FldSerDesTokens> {
+    let (terminator, consume) = match until_attr(&fld.attrs) {
+        Until::Set { terminator, consume } => (terminator, consume),
+        Until::NotSet => return None,
+    };
+    let ty = &fld.ty;
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    if !matches!(
+        fld_type,
+        FieldType::VecBytes { .. } | FieldType::VecNumerics { .. } | FieldType::VecStructs { .. } | FieldType::Struct { .. }
+    ) {
+        panic!("{struct_name}.{fld_name} #[byteserde(until = ..)] is only supported on Vec<T>, String & StringAscii like members, use #[byteserde(until_version = ..)] to gate a member on protocol version");
+    }
+    if let Deplete::Size(_) = deplete_attr(&fld.attrs) {
+        panic!("{struct_name}.{fld_name} #[byteserde(until = ..)] can't be combined with #[byteserde(deplete( .. ))]");
+    }
+    if let Replace::Set(_) = replace_or_expect_attr(&fld.attrs) {
+        panic!("{struct_name}.{fld_name} #[byteserde(until = ..)] can't be combined with #[byteserde(replace( .. ))] or #[byteserde(expect( .. ))]");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let ser_endian_method_xx = ser_endian_method_xx(&endian);
+    let des_endian_method_xx = des_endian_method_xx(&endian);
+
+    let elm = match fld_type {
+        FieldType::VecStructs { vec_ty } => Some(elm_tokens(
+            vec_ty,
+            &Ident::new("__elm", Span::call_site()),
+            &endian,
+            Nested::of(ast, fld),
+            0,
+            None,
+            &format!("{struct_name}.{fld_name}"),
+        )),
+        _ => None,
+    };
+    let ser_error = format!("{struct_name}.{fld_name} #[byteserde(until = ..)] serialized payload contains terminator: {{:02x?}}");
+    let missing_error = format!("{struct_name}.{fld_name} #[byteserde(until = ..)] terminator: {{:02x?}} not found at an element boundary");
+    let ser_payload = |byte_serialize_xxx: &str| match fld_type {
+        FieldType::VecBytes { .. } => quote!( ser.serialize_bytes_slice(&#var_name[..])?; ),
+        FieldType::Struct { ty } if is_string(ty) => quote!( ser.serialize_bytes_slice(#var_name.as_bytes())?; ),
+        FieldType::VecNumerics { .. } => quote!( for n in #var_name.iter() { ser.#ser_endian_method_xx(*n)?; } ),
+        FieldType::VecStructs { .. } => {
+            let elm = elm.as_ref().unwrap();
+            let elm_ser = if byte_serialize_xxx == "byte_serialize_stack" { &elm.ser_stck } else { &elm.ser_heap };
+            quote!( for __elm in #var_name.iter() { #elm_ser } )
+        }
+        FieldType::Struct { .. } => ser_nested(&endian, Nested::of(ast, fld), quote!(#var_name), byte_serialize_xxx),
+        _ => unreachable!(),
+    };
+    let ser_terminator = match consume {
+        true => quote!( ser.serialize_bytes_slice(__until)?; ),
+        false => quote!(),
+    };
+    // Vec<T> elements wider than a byte are only matched against the terminator at element boundaries
+    let elm_len = match fld_type {
+        FieldType::VecNumerics { vec_ty } => Some(numeric_size_of(vec_ty)),
+        FieldType::VecStructs { .. } => Some(elm.as_ref().unwrap().len_or_size()),
+        _ => None,
+    };
+    let ser_check = match &elm_len {
+        Some(elm_len) => quote!(
+            let mut __at = __start;
+            for __elm in #var_name.iter() {
+                if ::byteserde::utils::bytes::starts_with_terminator(&ser.as_slice()[__at..], __until) {
+                    return Err(::byteserde::prelude::SerDesError::new(format!(#ser_error, __until)));
+                }
+                __at += #elm_len;
+            }
+        ),
+        None => quote!(if ::byteserde::utils::bytes::position_of(&ser.as_slice()[__start..], __until).is_some() {
+            return Err(::byteserde::prelude::SerDesError::new(format!(#ser_error, __until)));
+        }),
+    };
+    let ser_uses = |byte_serialize_xxx: &str| {
+        let ser_payload = ser_payload(byte_serialize_xxx);
+        quote!(
+            let __until: &[u8] = #terminator;
+            let __start = ser.len();
+            #ser_payload
+            #ser_check
+            #ser_terminator
+        )
+    };
+
+    let des_elements = |des_element: TokenStream| {
+        quote!(
+            let mut #var_name: #ty = vec![];
+            loop {
+                let __at_terminator = des
+                    .peek_bytes_slice(__until.len())
+                    .map_err(|_| ::byteserde::prelude::SerDesError::new(format!(#missing_error, __until)))?
+                    == __until;
+                if __at_terminator {
+                    break;
+                }
+                #var_name.push(#des_element);
+            }
+        )
+    };
+    let des_payload = match fld_type {
+        FieldType::VecBytes { .. } => quote!( let #var_name: #ty = des.deserialize_bytes_slice(__len)?.to_vec(); ),
+        // String's own impl is length prefixed, hence only utf-8 bytes are taken here
+        FieldType::Struct { ty } if is_string(ty) => {
+            let utf8_error = format!("{struct_name}.{fld_name} #[byteserde(until = ..)] bytes are not a valid utf-8 string: {{}}");
+            quote!(
+                let #var_name: #ty = ::std::string::String::from_utf8(des.deserialize_bytes_slice(__len)?.to_vec())
+                    .map_err(|e| ::byteserde::prelude::SerDesError::new(format!(#utf8_error, e)))?;
+            )
+        }
+        FieldType::Struct { .. } => {
+            let des = des_nested(&endian, Nested::of(ast, fld), Some(quote!(__len)));
+            quote!( let #var_name: #ty = #des; )
+        }
+        FieldType::VecNumerics { .. } => des_elements(quote!( des.#des_endian_method_xx()? )),
+        FieldType::VecStructs { .. } => des_elements(elm.as_ref().unwrap().des.clone()),
+        _ => unreachable!(),
+    };
+    let des_terminator = match consume {
+        true => quote!( des.deserialize_bytes_slice(__until.len())?; ),
+        false => quote!(),
+    };
+
+    let payload_len = match fld_type {
+        FieldType::VecBytes { vec_ty } | FieldType::VecNumerics { vec_ty } => {
+            let elm_size = numeric_size_of(vec_ty);
+            quote!( #elm_size * self.#fld_name.len() )
+        }
+        FieldType::Struct { ty } if is_string(ty) => quote!( self.#fld_name.len() ),
+        FieldType::VecStructs { .. } => {
+            let elm_len = elm.as_ref().unwrap().len_or_size();
+            quote!( self.#fld_name.iter().map(|__elm| #elm_len).sum::<usize>() )
+        }
+        _ => quote!( self.#fld_name.byte_len() ),
+    };
+    let len_of = match consume {
+        true => quote!( (#payload_len + { let __until: &[u8] = #terminator; __until.len() }) ),
+        false => quote!( (#payload_len) ),
+    };
+
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl: quote!(),
+        ser_uses_stck: ser_uses("byte_serialize_stack"),
+        ser_uses_heap: ser_uses("byte_serialize_heap"),
+        des_vars: match elm_len {
+            Some(_) => quote!(
+                let __until: &[u8] = #terminator;
+                #des_payload
+                #des_terminator
+            ),
+            None => quote!(
+                let __until: &[u8] = #terminator;
+                let __len = des.peek_until(__until)?;
+                #des_payload
+                #des_terminator
+            ),
+        },
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!(0),
+        size_errors: size_unknown_error(struct_name, &fld_name, " terminated by #[byteserde(until = ..)]"),
+        len_of,
+    })
+}
+/// `#[byteserde(as = "u8")]` member is converted to & from a numeric wire type using `TryFrom`, hence `From` impls work as well.
+/// Conversion failures are returned as [SerDesError](::byteserde::prelude::SerDesError) by both serializer & deserializer.
+fn setup_as(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent) -> Option<FldSerDesTokens> {
+    let as_ty = as_attr(&fld.attrs)?;
+    let ty = &fld.ty;
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    let name = format!("{struct_name}.{fld_name} #[byteserde(as = \"{}\")]", quote!(#as_ty));
+    if !matches!(map_field_type(&as_ty), FieldType::Byte { .. } | FieldType::Numeric { .. }) {
+        panic!("{name} is only supported with numeric wire types, ex: \"u8\", \"u64\", \"U24\"");
+    }
+    if !matches!(deplete_attr(&fld.attrs), Deplete::NotSet) || !matches!(vec_len_attr(&fld.attrs), VecLen::NotSet) {
+        panic!("{name} can't be combined with #[byteserde(deplete( .. ))], #[byteserde(count( .. ))] or #[byteserde(byte_len( .. ))]");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let wire = elm_tokens(&as_ty, &Ident::new("__as", Span::call_site()), &endian, Nested::default(), 0, None, &name);
+
+    let ser_error = format!("{name} member can't be converted to {}", quote!(#as_ty));
+    let ser_uses = |wire_ser: &TokenStream| {
+        quote!(
+            let __as: &#as_ty = &<#as_ty as ::std::convert::TryFrom<#ty>>::try_from(::std::clone::Clone::clone(#var_name))
+                .map_err(|_| ::byteserde::prelude::SerDesError::new(#ser_error.to_string()))?;
+            #wire_ser
+        )
+    };
+    let ser_repl = match replace_or_expect_attr(&fld.attrs) {
+        Replace::Set(value) => quote!( let #var_name: &#ty = &#value; ),
+        Replace::NotSet => quote!(),
+    };
+    let des_error = format!("{name} value {{}} can't be converted to {}", quote!(#ty));
+    let wire_des = &wire.des;
+    let des_vars = quote!(
+        let __as: #as_ty = #wire_des;
+        let #var_name: #ty = <#ty as ::std::convert::TryFrom<#as_ty>>::try_from(__as)
+            .map_err(|_| ::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::UnexpectedValue, format!(#des_error, __as)))?;
+    );
+    let des_vars = des_expect(ast, fld, var_name, member, des_vars, |value| quote!( let __expected: #ty = #value; ));
+    let size = wire.size.clone().expect("numeric wire type");
+
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl,
+        ser_uses_stck: ser_uses(&wire.ser_stck),
+        ser_uses_heap: ser_uses(&wire.ser_heap),
+        des_vars,
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: size.clone(),
+        size_errors: vec![],
+        len_of: size,
+    })
+}
+/// `Option<T>` member is written when it is `Some` and is read when it is flagged as present by one of:
+/// * `#[byteserde(when = expr)]` - `expr` evaluates to `true`, it can reference members which precede it
+/// * `#[byteserde(presence = "flag")]` - preceding `u8` flag is `1`
+/// * struct level `#[byteserde(presence_map = "u16")]` - member's bit is set in the bitmap at the start of the struct
+fn setup_presence(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent) -> Option<FldSerDesTokens> {
+    let ty = &fld.ty;
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    let (presence, attr) = match (presence_attr(&fld.attrs), presence_map_bit(ast, fld)) {
+        (Presence::NotSet, None) => return None,
+        (Presence::When(when), _) => (quote!( #when ), "when = .."),
+        (Presence::Flag, _) => (quote!(__presence_flag), "presence = \"flag\""),
+        (Presence::NotSet, Some(bit)) => (quote!( __presence_map & (1 << #bit) != 0 ), "presence_map = .."),
+    };
+    let opt_ty = match option_inner_ty(ty) {
+        Some(opt_ty) => opt_ty,
+        None => panic!("{struct_name}.{fld_name} #[byteserde({attr})] is only supported on Option<T> members"),
+    };
+    if let (Replace::Set(_), _) | (_, PeekEq::Set(_)) = (replace_or_expect_attr(&fld.attrs), eq_attr(&fld.attrs)) {
+        panic!("{struct_name}.{fld_name} #[byteserde({attr})] can't be combined with #[byteserde(replace( .. ))], #[byteserde(expect( .. ))] or #[byteserde(eq( .. ))]");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let some = elm_tokens(
+        opt_ty,
+        &Ident::new("__some", Span::call_site()),
+        &endian,
+        Nested::of(ast, fld),
+        0,
+        Some(&deplete_attr(&fld.attrs)),
+        &format!("{struct_name}.{fld_name}"),
+    );
+    let (ser_some_stck, ser_some_heap, des_some) = (&some.ser_stck, &some.ser_heap, &some.des);
+    let len_some = some.len_or_size();
+    let flag_error = format!("{struct_name}.{fld_name} #[byteserde(presence = \"flag\")] expected: 0 or 1 actual: {{}}");
+    let (ser_flag_some, ser_flag_none, des_flag, len_flag) = match presence_attr(&fld.attrs) {
+        Presence::Flag => (
+            quote!( ser.serialize_ne(1_u8)?; ),
+            quote!( ser.serialize_ne(0_u8)?; ),
+            quote!(
+                let __presence_flag = match des.deserialize_u8()? {
+                    0 => false,
+                    1 => true,
+                    v => return Err(::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::UnexpectedValue, format!(#flag_error, v))),
+                };
+            ),
+            quote!( + 1 ),
+        ),
+        _ => (quote!(), quote!(), quote!(), quote!()),
+    };
+    let ser_uses = |ser_some: &TokenStream| {
+        quote!(
+            match #var_name {
+                Some(__some) => { #ser_flag_some #ser_some }
+                None => { #ser_flag_none }
+            }
+        )
+    };
+
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl: quote!(),
+        ser_uses_stck: ser_uses(ser_some_stck),
+        ser_uses_heap: ser_uses(ser_some_heap),
+        des_vars: quote!(
+            #des_flag
+            let #var_name: #ty = if #presence { Some(#des_some) } else { None };
+        ),
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!(0),
+        size_errors: size_unknown_error(struct_name, &fld_name, &format!(" with #[byteserde({attr})]")),
+        len_of: quote!( (match &self.#fld_name { Some(__some) => #len_some, None => 0 } #len_flag) ),
+    })
+}
+/// bit assigned to the `Option<T>` member in the struct level `#[byteserde(presence_map = "..")]`, members are assigned bits in declaration order starting with the least significant bit
+fn presence_map_bit(ast: &DeriveInput, fld: &Field) -> Option<usize> {
+    presence_map_attr(&ast.attrs)?;
+    presence_map_members(ast).iter().position(|f| std::ptr::eq(*f, fld))
+}
+/// `Option<T>` members which are not skipped and have no other presence attribute
+fn presence_map_members(ast: &DeriveInput) -> Vec<&Field> {
+    match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .filter(|f| option_inner_ty(&f.ty).is_some() && matches!(presence_attr(&f.attrs), Presence::NotSet) && matches!(skip_attr(&f.attrs), Skip::NotSet))
+            // `with = ..` & `as = ..` members encode their own presence and ignore the bitmap
+            .filter(|f| with_attr(&f.attrs).is_none() && as_attr(&f.attrs).is_none())
+            .collect(),
+        _ => vec![],
+    }
+}
+/// writes & reads the struct level `#[byteserde(presence_map = "..")]` bitmap ahead of all members
+fn setup_presence_map(ast: &DeriveInput, flds_tokens: &mut Vec<FldSerDesTokens>) {
+    let map_ty = match presence_map_attr(&ast.attrs) {
+        Some(map_ty) => map_ty,
+        None => return,
+    };
+    let struct_name = &ast.ident;
+    let members = match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| presence_map_members(ast).iter().any(|m| std::ptr::eq(*m, *f)))
+            .map(|(idx, f)| member_of(idx, f))
+            .collect::<Vec<_>>(),
+        _ => panic!("{struct_name} #[byteserde(presence_map = \"{map_ty}\")] is not supported on enums"),
+    };
+    let bits = match map_ty.to_string().as_str() {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" => 32,
+        _ => 64,
+    };
+    if members.is_empty() || members.len() > bits {
+        panic!(
+            "{struct_name} #[byteserde(presence_map = \"{map_ty}\")] requires between 1 and {bits} Option<T> members, found {}",
+            members.len()
+        );
+    }
+    let endian = endian_attr(&ast.attrs, &[]);
+    let ser_endian_method_xx = ser_endian_method_xx(&endian);
+    let des_endian_method_xx = des_endian_method_xx(&endian);
+    let bit = 0..members.len();
+    let ser_map = quote!(
+        let mut __presence_map: #map_ty = 0;
+        #( if self.#members.is_some() { __presence_map |= 1 << #bit; } )*
+        ser.#ser_endian_method_xx(__presence_map)?;
+    );
+    flds_tokens.insert(
+        0,
+        FldSerDesTokens {
+            ser_vars: quote!(),
+            ser_repl: quote!(),
+            ser_uses_stck: ser_map.clone(),
+            ser_uses_heap: ser_map,
+            des_vars: quote!( let __presence_map: #map_ty = des.#des_endian_method_xx()?; ),
+            des_peeked: quote!(),
+            des_uses: quote!(),
+            des_errors: vec![],
+            size_of: quote!( ::std::mem::size_of::<#map_ty>() ),
+            size_errors: vec![],
+            len_of: quote!( ::std::mem::size_of::<#map_ty>() ),
+        },
+    );
+}
+/// members annotated with `#[byteserde(since = N)]` and/or `#[byteserde(until_version = N)]` are only serialized & deserialized when the protocol version
+/// set on the serializer or deserializer is in range, otherwise deserialized as `Default`. When version is not set all members are present.
+fn setup_version(ast: &DeriveInput, flds_tokens: &mut [FldSerDesTokens]) {
+    let flds = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => return,
+    };
+    for (idx, fld) in flds.iter().enumerate() {
+        let (since, until) = match version_attr(&fld.attrs) {
+            Version::Set { since, until } => (since, until),
+            Version::NotSet => continue,
+        };
+        let member = member_of(idx, fld);
+        if !matches!(skip_attr(&fld.attrs), Skip::NotSet) {
+            panic!(
+                "{}.{} #[byteserde(since = ..)] and #[byteserde(until_version = ..)] can't be combined with #[byteserde(skip)]",
+                ast.ident,
+                quote!(#member)
+            );
+        }
+        let in_range = match (since, until) {
+            (Some(since), Some(until)) => quote!( (#since..=#until).contains(&__version) ),
+            (Some(since), None) => quote!( __version >= #since ),
+            (None, Some(until)) => quote!( __version <= #until ),
+            (None, None) => unreachable!(),
+        };
+        let ty = &fld.ty;
+        let var_name = match &member {
+            Member::Named(fld_name) => fld_name.clone(),
+            Member::Unnamed(fld_index) => Ident::new(&format!("_{}", fld_index.index), Span::call_site()),
+        };
+        let fld_tokens = &mut flds_tokens[idx];
+        let (ser_uses_stck, ser_uses_heap, des_vars) = (&fld_tokens.ser_uses_stck, &fld_tokens.ser_uses_heap, &fld_tokens.des_vars);
+        fld_tokens.ser_uses_stck = quote!( if ser.version().map_or(true, |__version| #in_range) { #ser_uses_stck } );
+        fld_tokens.ser_uses_heap = quote!( if ser.version().map_or(true, |__version| #in_range) { #ser_uses_heap } );
+        fld_tokens.des_vars = quote!(
+            let #var_name: #ty = match des.version().map_or(true, |__version| #in_range) {
+                true => {
+                    #des_vars
+                    #var_name
+                }
+                false => ::std::default::Default::default(),
+            };
+        );
+        fld_tokens.size_errors.push(format!(
+            "trait ByteSerializedSizeOf can't be implemented for struct {} because it has a member {} with #[byteserde(since = ..)] or #[byteserde(until_version = ..)] whose presence depends on protocol version",
+            ast.ident,
+            quote!(#member)
+        ));
+    }
+}
+/// SBE style repeating group, `Vec<T>` is preceded by a header with the block length of a single element and the number of elements.
+/// Each element is deserialized from a deserializer bounded to the block length, hence any trailing bytes unknown to `T` are skipped
+fn setup_group(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent, fld_type: &FieldType) -> Option<FldSerDesTokens> {
+    let Group { block_len, count } = group_attr(&fld.attrs)?;
+    let ty = &fld.ty;
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    if !matches!(vec_len_attr(&fld.attrs), VecLen::NotSet) || !matches!(deplete_attr(&fld.attrs), Deplete::NotSet) {
+        panic!("{struct_name}.{fld_name} #[byteserde(group( .. ))] can't be combined with #[byteserde(count( .. ))], #[byteserde(byte_len( .. ))] or #[byteserde(deplete( .. ))]");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let ser_endian_method_xx = ser_endian_method_xx(&endian);
+    let des_endian_method_xx = des_endian_method_xx(&endian);
+
+    // elements are read from a bounded deserializer, hence member level endian is not set on either side
+    let nested = Nested {
+        inherit: false,
+        ..Nested::of(ast, fld)
+    };
+    let (elm_size, is_struct, des_elm) = match fld_type {
+        FieldType::VecBytes { vec_ty } | FieldType::VecNumerics { vec_ty } => (numeric_size_of(vec_ty), false, quote!( __des.#des_endian_method_xx()? )),
+        FieldType::VecStructs { vec_ty } => (
+            quote!( <#vec_ty as ::byteserde::size::ByteSerializedSizeOf>::BYTE_SIZE ),
+            true,
+            match nested.ctx {
+                true => quote!(__des.deserialize_with(ctx)?),
+                false => quote!(__des.deserialize()?),
+            },
+        ),
+        _ => panic!("{struct_name}.{fld_name} #[byteserde(group( .. ))] is only supported on Vec<T> members"),
+    };
+    let overflow_error = format!("{struct_name}.{fld_name} #[byteserde(group( .. ))] {{}} {{}} does not fit into {{}}");
+    let ser_uses = |xxx: &str| {
+        let ser_elm = match is_struct {
+            true => ser_nested(&endian, nested, quote!(n), xxx),
+            false => quote!( ser.#ser_endian_method_xx(*n)?; ),
+        };
+        quote!(
+            let __block_len = <#block_len as ::std::convert::TryFrom<usize>>::try_from(#elm_size)
+                .map_err(|_| ::byteserde::prelude::SerDesError::new(format!(#overflow_error, "block_len", #elm_size, stringify!(#block_len))))?;
+            let __count = <#count as ::std::convert::TryFrom<usize>>::try_from(#var_name.len())
+                .map_err(|_| ::byteserde::prelude::SerDesError::new(format!(#overflow_error, "count", #var_name.len(), stringify!(#count))))?;
+            ser.#ser_endian_method_xx(__block_len)?;
+            ser.#ser_endian_method_xx(__count)?;
+            for n in #var_name.iter() {
+                #ser_elm
+            }
+        )
+    };
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl: quote!(),
+        ser_uses_stck: ser_uses("byte_serialize_stack"),
+        ser_uses_heap: ser_uses("byte_serialize_heap"),
+        des_vars: quote!(
+            let __block_len: #block_len = des.#des_endian_method_xx()?;
+            let __count: #count = des.#des_endian_method_xx()?;
+            let mut #var_name: #ty = vec![];
+            for _ in 0..__count {
+                let __des = &mut des.deserialize_bounded(__block_len as usize)?;
+                #var_name.push(#des_elm);
+            }
+        ),
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!(0),
+        size_errors: size_unknown_error(struct_name, &fld_name, " with #[byteserde(group( .. ))]"),
+        len_of: quote!( (::std::mem::size_of::<#block_len>() + ::std::mem::size_of::<#count>() + #elm_size * self.#fld_name.len()) ),
+    })
+}
+/// `HashMap`, `BTreeMap`, `IndexMap` & their set counterparts, each entry is a key followed by its value, see [elm_tokens] for
+/// supported key & value shapes. Number of entries comes from one of:
+/// * `#[byteserde(count_prefix = "u16")]` - numeric written ahead of the entries using the `endian` of the member
+/// * `#[byteserde(count( .. ))]` or `#[byteserde(deplete( .. ))]` - number of entries, expression can reference other members
+/// * `#[byteserde(byte_len( .. ))]` - number of bytes occupied by all of the entries
+/// * otherwise all remaining bytes
+///
+/// Entries are serialized in [MapOrder] and duplicate keys are rejected during deserialization.
+fn setup_map(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent, fld_type: &FieldType) -> Option<FldSerDesTokens> {
+    let ty = &fld.ty;
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    let count_prefix = count_prefix_attr(&fld.attrs);
+    let (order, key_ty, val_ty) = match fld_type {
+        FieldType::Map { order, key_ty, val_ty } => (*order, *key_ty, *val_ty),
+        _ if count_prefix.is_some() => panic!("{struct_name}.{fld_name} #[byteserde(count_prefix = ..)] is only supported on HashMap, BTreeMap, IndexMap, HashSet, BTreeSet & IndexSet members"),
+        _ => return None,
+    };
+    if let Replace::Set(_) = replace_or_expect_attr(&fld.attrs) {
+        panic!("{struct_name}.{fld_name} #[byteserde(replace( .. ))] and #[byteserde(expect( .. ))] are not supported on map & set members");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let ser_endian_method_xx = ser_endian_method_xx(&endian);
+    let des_endian_method_xx = des_endian_method_xx(&endian);
+    let name = format!("{struct_name}.{fld_name}");
+    let key = elm_tokens(key_ty, &Ident::new("__key", Span::call_site()), &endian, Nested::of(ast, fld), 0, None, &name);
+    let val = val_ty.map(|val_ty| elm_tokens(val_ty, &Ident::new("__val", Span::call_site()), &endian, Nested::of(ast, fld), 0, None, &name));
+
+    // serializer
+    let entries = match (order, val_ty) {
+        (MapOrder::Sorted | MapOrder::Insertion, _) => quote!( #var_name.iter() ),
+        (MapOrder::Hashed, Some(_)) => quote!( { let mut __entries = #var_name.iter().collect::<::std::vec::Vec<_>>(); __entries.sort_unstable_by(|a, b| a.0.cmp(b.0)); __entries } ),
+        (MapOrder::Hashed, None) => quote!( { let mut __entries = #var_name.iter().collect::<::std::vec::Vec<_>>(); __entries.sort_unstable(); __entries } ),
+    };
+    let overflow_error = format!("{name} #[byteserde(count_prefix = ..)] count {{}} does not fit into {{}}");
+    let ser_count = match &count_prefix {
+        Some(prefix) => quote!(
+            let __count = <#prefix as ::std::convert::TryFrom<usize>>::try_from(#var_name.len())
+                .map_err(|_| ::byteserde::prelude::SerDesError::new(format!(#overflow_error, #var_name.len(), stringify!(#prefix))))?;
+            ser.#ser_endian_method_xx(__count)?;
+        ),
+        None => quote!(),
+    };
+    let ser_uses = |key_ser: &TokenStream, val_ser: Option<&TokenStream>| match val_ser {
+        Some(val_ser) => quote!( #ser_count for (__key, __val) in #entries { #key_ser #val_ser } ),
+        None => quote!( #ser_count for __key in #entries { #key_ser } ),
+    };
+
+    // deserializer
+    let duplicate_error = format!("{name} duplicate key in entry {{}}");
+    let key_des = &key.des;
+    let entry = match &val {
+        Some(val) => {
+            let val_des = &val.des;
+            quote!(
+                let __key = #key_des;
+                let __val = #val_des;
+                if #var_name.insert(__key, __val).is_some() {
+                    return Err(::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::UnexpectedValue, format!(#duplicate_error, #var_name.len())));
+                }
+            )
+        }
+        None => quote!(
+            let __key = #key_des;
+            if !#var_name.insert(__key) {
+                return Err(::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::UnexpectedValue, format!(#duplicate_error, #var_name.len())));
+            }
+        ),
+    };
+    let des_entries = match (&count_prefix, deplete_attr(&fld.attrs), vec_len_attr(&fld.attrs)) {
+        (Some(prefix), Deplete::NotSet, VecLen::NotSet) => quote!( let __count: #prefix = des.#des_endian_method_xx()?; for _ in 0..__count { #entry } ),
+        (None, Deplete::Size(count), VecLen::NotSet) | (None, Deplete::NotSet, VecLen::Count(count)) => quote!( for _ in 0..(#count) as usize { #entry } ),
+        (None, Deplete::NotSet, VecLen::ByteLen(byte_len)) => quote!(
+            {
+                let des = &mut des.deserialize_bounded( (#byte_len) as usize )?;
+                while !des.is_empty() { #entry }
+            }
+        ),
+        (None, Deplete::NotSet, VecLen::NotSet) => quote!( while !des.is_empty() { #entry } ),
+        _ => panic!("{name} #[byteserde(count_prefix = ..)] can't be combined with #[byteserde(count( .. ))], #[byteserde(byte_len( .. ))] or #[byteserde(deplete( .. ))]"),
+    };
+
+    let key_len = key.len_or_size();
+    let (entry_len, entry) = match &val {
+        Some(val) => {
+            let val_len = val.len_or_size();
+            (quote!( #key_len + #val_len ), quote!((__key, __val)))
+        }
+        None => (key_len, quote!(__key)),
+    };
+    let count_len = match &count_prefix {
+        Some(prefix) => quote!( ::std::mem::size_of::<#prefix>() + ),
+        None => quote!(),
+    };
+
+    Some(FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl: quote!(),
+        ser_uses_stck: ser_uses(&key.ser_stck, val.as_ref().map(|val| &val.ser_stck)),
+        ser_uses_heap: ser_uses(&key.ser_heap, val.as_ref().map(|val| &val.ser_heap)),
+        des_vars: quote!(
+            let mut #var_name: #ty = ::std::default::Default::default();
+            #des_entries
+        ),
+        des_peeked: quote!(),
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of: quote!(0),
+        size_errors: size_unknown_error(struct_name, &fld_name, " of map or set type"),
+        len_of: quote!( (#count_len self.#fld_name.iter().map(|#entry| #entry_len).sum::<usize>()) ),
+    })
+}
+/// `size_errors` of a member whose size is only known at run time, `shape` describes the member, ex: " of Vec type"
+fn size_unknown_error(struct_name: impl std::fmt::Display, fld_name: impl std::fmt::Display, shape: &str) -> Vec<String> {
+    vec![format!("trait ByteSerializedSizeOf can't be implemented for struct {struct_name} because it has a member {fld_name}{shape} whose size is not known at compile time")]
+}
+fn option_inner_ty(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(TypePath { path, .. }) if path.segments.len() == 1 && path.segments[0].ident == "Option" => match &path.segments[0].arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => match args.first() {
+                Some(GenericArgument::Type(opt_ty)) => Some(opt_ty),
+                _ => None,
+            },
+            _ => None,
+        },
+        Type::Group(TypeGroup { elem, .. }) => option_inner_ty(elem),
+        _ => None,
+    }
+}
+fn is_string(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last().map_or(false, |seg| seg.ident == "String"),
+        Type::Group(TypeGroup { elem, .. }) => is_string(elem),
+        _ => false,
+    }
+}
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last().map_or(false, |seg| seg.ident == "PhantomData"),
+        Type::Group(TypeGroup { elem, .. }) => is_phantom_data(elem),
+        _ => false,
+    }
+}
+
+/// appends a comparison of the deserialized value against `#[byteserde(expect( .. ))]` expression, `expected` yields `__expected` variable
+fn des_expect(ast: &DeriveInput, fld: &Field, var_name: &Ident, member: &MemberIdent, des_vars: TokenStream, expected: impl Fn(&Expr) -> TokenStream) -> TokenStream {
+    let value = match expect_attr(&fld.attrs) {
+        Expect::Set(value) => value,
+        Expect::NotSet => return des_vars,
+    };
+    let error = match member {
+        MemberIdent::Named(fld_name) => format!("{}.{} #[byteserde(expect( .. ))] expected: {{:?}} actual: {{:?}}", ast.ident, fld_name),
+        MemberIdent::Unnamed(fld_index) => format!("{}.{} #[byteserde(expect( .. ))] expected: {{:?}} actual: {{:?}}", ast.ident, quote!(#fld_index)),
+    };
+    let expected = expected(&value);
+    quote!(
+        #des_vars
+        #expected
+        if #var_name != __expected {
+            return Err(::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::UnexpectedValue, format!(#error, __expected, #var_name)));
+        }
+    )
+}
+
 fn setup_numeric(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member: &MemberIdent, option: &FieldType) -> FldSerDesTokens {
-    let replace = replace_attr(&fld.attrs);
+    let replace = replace_or_expect_attr(&fld.attrs);
+    let backfill_len = backfill_len_attr(&fld.attrs);
+    if let (Replace::Set(_), BackfillLen::Of(_)) = (&replace, &backfill_len) {
+        panic!("{}.{} can't have both #[byteserde(replace( .. ))] and #[byteserde(backfill_len( .. ))]", ast.ident, var_name);
+    }
+    let checksum = checksum_attr(&fld.attrs);
+    if let (Checksum::Set { .. }, Replace::Set(_), _) | (Checksum::Set { .. }, _, BackfillLen::Of(_)) = (&checksum, &replace, &backfill_len) {
+        panic!(
+            "{}.{} can't combine #[byteserde(checksum = \"..\")] with #[byteserde(replace( .. ))] or #[byteserde(backfill_len( .. ))]",
+            ast.ident, var_name
+        );
+    }
     let endian = endian_attr(&ast.attrs, &fld.attrs);
     let ser_endian_method_xx = ser_endian_method_xx(&endian);
     let des_endian_method_xx = des_endian_method_xx(&endian);
@@ -160,9 +1304,28 @@ fn setup_numeric(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, me
         Replace::NotSet => quote!(),
     };
 
-    let ser_uses_xxx = match option {
-        FieldType::Byte { .. } => quote!( ser.serialize_bytes_slice(&[#var_name as u8])?; ),
-        FieldType::Numeric { .. } => quote!( ser.#ser_endian_method_xx(#var_name)?; ),
+    let fld_member = match member {
+        MemberIdent::Named(fld_name) => Member::Named((*fld_name).clone()),
+        MemberIdent::Unnamed(fld_index) => (*fld_index).clone(),
+    };
+    let ser_uses_xxx = match (option, &checksum, backfill_len) {
+        (_, Checksum::Set { algorithm, .. }, _) => {
+            let start = checksum_start_ident(&fld_member);
+            let ser_uses = match option {
+                FieldType::Byte { .. } => quote!( ser.serialize_bytes_slice(&[#var_name as u8])?; ),
+                _ => quote!( ser.#ser_endian_method_xx(#var_name)?; ),
+            };
+            quote!(
+                let #var_name: #ty = <::byteserde::checksum::#algorithm as ::byteserde::checksum::Checksum>::checksum(&ser.as_slice()[#start..]);
+                #ser_uses
+            )
+        }
+        (_, _, BackfillLen::Of(_)) => {
+            let reserved = backfill_len_reserved_ident(&fld_member);
+            quote!( let #reserved = ser.reserve::<#ty>()?; )
+        }
+        (FieldType::Byte { .. }, _, _) => quote!( ser.serialize_bytes_slice(&[#var_name as u8])?; ),
+        (FieldType::Numeric { .. }, _, _) => quote!( ser.#ser_endian_method_xx(#var_name)?; ),
         _ => panic!("this method should only be called Byte, Numeric types"),
     };
 
@@ -174,6 +1337,22 @@ fn setup_numeric(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, me
         FieldType::Numeric { .. } => quote!( let #var_name: #ty = des.#des_endian_method_xx()?; ),
         _ => panic!("this method should only be called Byte, Numeric types"),
     };
+    let des_vars = match checksum {
+        Checksum::Set { algorithm, .. } => {
+            let start = checksum_start_ident(&fld_member);
+            let error = format!("{}.{} #[byteserde(checksum = \"{{}}\")] mismatch, expected: {{:#x}} actual: {{:#x}}", ast.ident, quote!(#fld_member));
+            quote!(
+                let __actual: #ty = <::byteserde::checksum::#algorithm as ::byteserde::checksum::Checksum>::checksum(des.consumed_since(#start));
+                #des_vars
+                if #var_name != __actual {
+                    let algorithm = <::byteserde::checksum::#algorithm as ::byteserde::checksum::Checksum>::NAME;
+                    return Err(::byteserde::prelude::SerDesError::with_kind(::byteserde::error::ErrorKind::ChecksumMismatch { algorithm, expected: #var_name.into(), actual: __actual.into() }, format!(#error, algorithm, #var_name, __actual)));
+                }
+            )
+        }
+        Checksum::NotSet => des_vars,
+    };
+    let des_vars = des_expect(ast, fld, var_name, member, des_vars, |value| quote!( let __expected: #ty = (#value) as #ty; ));
 
     FldSerDesTokens {
         ser_vars,
@@ -184,17 +1363,19 @@ fn setup_numeric(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, me
         des_peeked: quote!(), // does not apply here
         des_uses: quote!( #var_name, ),
         des_errors: vec![],
-        size_of: quote!( ::std::mem::size_of::<#ty>() ),
+        size_of: numeric_size_of(ty),
         size_errors: vec![],
-        len_of: quote!( ::std::mem::size_of::<#ty>() ),
+        len_of: numeric_size_of(ty),
     }
 }
 
 fn setup_array(ast: &DeriveInput, fld: &Field, arr_ty: &Type, var_name: &Ident, len: &Expr, member: &MemberIdent, option: &FieldType) -> FldSerDesTokens {
-    let replace = replace_attr(&fld.attrs);
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    let replace = replace_or_expect_attr(&fld.attrs);
     let endian = endian_attr(&ast.attrs, &fld.attrs);
-    let ser_endian_method_xx = ser_endian_method_xx(&endian);
-    let des_endian_method_xx = des_endian_method_xx(&endian);
 
     let ser_vars = match member {
         MemberIdent::Named(fld_name) => {
@@ -208,48 +1389,21 @@ fn setup_array(ast: &DeriveInput, fld: &Field, arr_ty: &Type, var_name: &Ident,
         Replace::Set(value) => quote!( let #var_name: &[#arr_ty; #len] = &#value; ),
         Replace::NotSet => quote!(),
     };
-    let ser_uses_xxx = |byte_serialize_xxx: &Ident| match option {
-        FieldType::ArrBytes { signed, .. } => match signed {
-            false => quote!( ser.serialize_bytes_slice(#var_name)?; ),
-            true => {
-                quote!( let #var_name: &[u8; #len] = unsafe { ::std::mem::transmute(#var_name) };
-                        ser.serialize_bytes_slice( #var_name )?; )
-            }
-        },
-        FieldType::ArrNumerics { .. } => {
-            quote!( for n in #var_name { ser.#ser_endian_method_xx(*n)?; } )
-        }
-        FieldType::ArrStructs { .. } => {
-            quote!( for n in #var_name { n.#byte_serialize_xxx(ser)?; } )
-        }
-        _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs types"),
-    };
-
-    let des_vars = match option {
-        FieldType::ArrBytes { signed, .. } => match signed {
-            false => {
-                quote!( let #var_name: [#arr_ty; #len] = *des.deserialize_bytes_array_ref()?; )
-            }
-            true => {
-                quote!( let #var_name: [u8; #len] = *des.deserialize_bytes_array_ref()?; 
-                        let #var_name: [#arr_ty; #len] = unsafe { ::std::mem::transmute(#var_name) }; )
-            }
-        },
-        FieldType::ArrNumerics { .. } => {
-            quote!( let mut #var_name: [#arr_ty; #len] = [0; #len]; for e in #var_name.iter_mut() {*e = des.#des_endian_method_xx()?;} )
-        }
-        FieldType::ArrStructs { .. } => {
-            quote!( let mut #var_name: [#arr_ty; #len] = [#arr_ty::default(); #len]; for e in #var_name.iter_mut() {*e = des.deserialize()?;} )
-        }
-        _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs types"),
-    };
-    let size = match option {
-        FieldType::ArrBytes { .. } | FieldType::ArrNumerics { .. } => {
-            quote!( ::std::mem::size_of::<#arr_ty>() * #len )
-        }
-        FieldType::ArrStructs { .. } => quote!( #arr_ty::byte_size() * #len ),
-        _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs types"),
+    let arr = match option {
+        FieldType::ArrBytes { .. } | FieldType::ArrNumerics { .. } | FieldType::ArrStructs { .. } | FieldType::ArrArrays { .. } => elm_tokens(
+            &parse_quote!( [#arr_ty; #len] ),
+            var_name,
+            &endian,
+            Nested::of(ast, fld),
+            0,
+            None,
+            &format!("{}.{}", ast.ident, fld_name),
+        ),
+        _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs, ArrayArrays types"),
     };
+    let arr_des = arr.des;
+    let des_vars = quote!( let #var_name: [#arr_ty; #len] = #arr_des; );
+    let des_vars = des_expect(ast, fld, var_name, member, des_vars, |value| quote!( let __expected: [#arr_ty; #len] = #value; ));
 
     let len_var = match member {
         MemberIdent::Named(fld_name) => {
@@ -259,21 +1413,17 @@ fn setup_array(ast: &DeriveInput, fld: &Field, arr_ty: &Type, var_name: &Ident,
             quote!( self.#fld_index )
         }
     };
-    let len = match option {
-        FieldType::ArrBytes { .. } | FieldType::ArrNumerics { .. } => {
-            quote!( (::std::mem::size_of::<#arr_ty>() * #len) )
-        }
-        FieldType::ArrStructs { .. } => {
-            quote!( ({ let mut len = 0; for e in #len_var.iter() { len += e.byte_len(); } len }) )
-        }
-        _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs types"),
+    let size = arr.size.expect("array of sized elements");
+    let len = match arr.len {
+        Some(arr_len) => quote!( ({ let #var_name = &#len_var; #arr_len }) ),
+        None => quote!( (#size) ),
     };
 
     FldSerDesTokens {
         ser_vars,
         ser_repl,
-        ser_uses_stck: ser_uses_xxx(&Ident::new("byte_serialize_stack", Span::call_site())),
-        ser_uses_heap: ser_uses_xxx(&Ident::new("byte_serialize_heap", Span::call_site())),
+        ser_uses_stck: arr.ser_stck,
+        ser_uses_heap: arr.ser_heap,
         des_vars,
         des_peeked: quote!(), // does not apply here
         des_uses: quote!( #var_name, ),
@@ -284,9 +1434,193 @@ fn setup_array(ast: &DeriveInput, fld: &Field, arr_ty: &Type, var_name: &Ident,
     }
 }
 
+/// Serialize, deserialize, size & len tokens of a single element of an array, tuple, `Box`, `Vec` or `Option`
+struct ElmTokens {
+    /// serializes `elm` reference
+    ser_stck: TokenStream,
+    ser_heap: TokenStream,
+    /// expression which deserializes the element
+    des: TokenStream,
+    /// `None` when the size of the element is not known at compile time, ex: `Vec<T>`
+    size: Option<TokenStream>,
+    /// `None` when the length of the element is the same as its size, otherwise an expression using `elm` reference
+    len: Option<TokenStream>,
+}
+impl ElmTokens {
+    fn len_or_size(&self) -> TokenStream {
+        match (&self.len, &self.size) {
+            (Some(len), _) | (None, Some(len)) => len.clone(),
+            (None, None) => unreachable!("element has neither len nor size"),
+        }
+    }
+}
+/// Tokens of an element of type `elm_ty` referenced by `elm`, shapes are handled recursively using `depth` to name the element of each level:
+/// * `[T; N]` - built one by one using `try_array_from_fn` hence `T` does not need to be `Copy` or `Default`, ex: `[[u16; 4]; 8]`
+/// * `(T0, T1, ..)` - members in declaration order, ex: `(u16, [u8; 4])`
+/// * `Box<T>` - same as `T`
+/// * `Vec<T>` - only when it is not nested inside of an array, tuple, map, set or another `Vec` since its length is not part of the byte stream,
+///   `deplete` is the number of elements (bytes for `Vec<u8>`) otherwise all remaining bytes are used
+///
+/// `deplete` is `None` for nested elements and otherwise applies to the innermost `Vec`, `String` or struct like type. `name` is used to report unsupported shapes.
+fn elm_tokens(elm_ty: &Type, elm: &Ident, endian: &Endian, nested_call: Nested, depth: usize, deplete: Option<&Deplete>, name: &str) -> ElmTokens {
+    let ser_endian_method_xx = ser_endian_method_xx(endian);
+    let des_endian_method_xx = des_endian_method_xx(endian);
+    let nested = &Ident::new(&format!("__elm{depth}"), Span::call_site());
+    let no_deplete = || {
+        if let Some(Deplete::Size(_)) = deplete {
+            panic!("{name} #[byteserde(deplete( .. ))] only applies to Vec, String & struct like types and not to numerics, arrays or tuples");
+        }
+    };
+    let struct_elm = || ElmTokens {
+        ser_stck: ser_nested(endian, nested_call, quote!(#elm), "byte_serialize_stack"),
+        ser_heap: ser_nested(endian, nested_call, quote!(#elm), "byte_serialize_heap"),
+        des: match deplete {
+            Some(Deplete::Size(size)) => des_nested(endian, nested_call, Some(quote!(#size))),
+            _ => des_nested(endian, nested_call, None),
+        },
+        size: Some(quote!( <#elm_ty as ::byteserde::size::ByteSerializedSizeOf>::BYTE_SIZE )),
+        len: Some(quote!( #elm.byte_len() )),
+    };
+    match elm_ty {
+        Type::Array(TypeArray { elem, len, .. }) => {
+            no_deplete();
+            let elem = elem.as_ref();
+            // byte arrays are serialized as a single slice
+            if let FieldType::Byte { signed, .. } = map_field_type(elem) {
+                let (ser, des) = match signed {
+                    false => (quote!( ser.serialize_bytes_slice(#elm)?; ), quote!( *des.deserialize_bytes_array_ref::<{ #len }>()? )),
+                    true => (
+                        quote!( let __bytes: &[u8; #len] = unsafe { ::std::mem::transmute(#elm) }; ser.serialize_bytes_slice(__bytes)?; ),
+                        quote!( { let __bytes: [u8; #len] = *des.deserialize_bytes_array_ref()?; unsafe { ::std::mem::transmute::<[u8; #len], [#elem; #len]>(__bytes) } } ),
+                    ),
+                };
+                return ElmTokens {
+                    ser_stck: ser.clone(),
+                    ser_heap: ser,
+                    des,
+                    size: Some(quote!( (#len) )),
+                    len: None,
+                };
+            }
+            let ElmTokens { ser_stck, ser_heap, des, size, len: elm_len } = elm_tokens(elem, nested, endian, nested_call, depth + 1, None, name);
+            ElmTokens {
+                ser_stck: quote!( for #nested in #elm.iter() { #ser_stck } ),
+                ser_heap: quote!( for #nested in #elm.iter() { #ser_heap } ),
+                des: quote!( ::byteserde::utils::std_types::try_array_from_fn(|_| -> ::byteserde::error::Result<#elem> { let #nested = #des; Ok(#nested) })? ),
+                size: size.map(|size| quote!( (#size * #len) )),
+                len: elm_len.map(|elm_len| quote!( #elm.iter().map(|#nested| #elm_len).sum::<usize>() )),
+            }
+        }
+        Type::Tuple(TypeTuple { elems, .. }) => {
+            no_deplete();
+            if elems.is_empty() {
+                panic!("{name} unit type `()` is not supported");
+            }
+            let nested = (0..elems.len()).map(|idx| Ident::new(&format!("__elm{depth}_{idx}"), Span::call_site())).collect::<Vec<_>>();
+            let elms = elems.iter().zip(nested.iter()).map(|(ty, nested)| elm_tokens(ty, nested, endian, nested_call, depth + 1, None, name)).collect::<Vec<_>>();
+            let (ser_stck, ser_heap, des) = (elms.iter().map(|e| &e.ser_stck), elms.iter().map(|e| &e.ser_heap), elms.iter().map(|e| &e.des));
+            let size = elms.iter().map(|e| e.size.clone()).collect::<Option<Vec<_>>>();
+            let len = match elms.iter().any(|e| e.len.is_some()) {
+                true => {
+                    let lens = elms.iter().map(|e| e.len_or_size());
+                    Some(quote!( { let ( #(#nested,)* ) = #elm; #(#lens)+* } ))
+                }
+                false => None,
+            };
+            ElmTokens {
+                ser_stck: quote!( { let ( #(#nested,)* ) = #elm; #(#ser_stck)* } ),
+                ser_heap: quote!( { let ( #(#nested,)* ) = #elm; #(#ser_heap)* } ),
+                des: quote!( ( #(#des,)* ) ),
+                size: size.map(|size| quote!( (#(#size)+*) )),
+                len,
+            }
+        }
+        Type::Group(TypeGroup { elem, .. }) => elm_tokens(elem, elm, endian, nested_call, depth, deplete, name),
+        Type::Path(TypePath { path, .. }) => match path_2_byte_numeric_vec_struct(path, elm_ty) {
+            FieldType::Byte { .. } | FieldType::Numeric { .. } => {
+                no_deplete();
+                ElmTokens {
+                    ser_stck: quote!( ser.#ser_endian_method_xx(*#elm)?; ),
+                    ser_heap: quote!( ser.#ser_endian_method_xx(*#elm)?; ),
+                    des: quote!( des.#des_endian_method_xx()? ),
+                    size: Some(numeric_size_of(elm_ty)),
+                    len: None,
+                }
+            }
+            FieldType::Composite { .. } => {
+                let box_ty = generic_arg_of(path, "Box").expect("Box<T>");
+                let ElmTokens { ser_stck, ser_heap, des, size, len } = elm_tokens(box_ty, nested, endian, nested_call, depth + 1, deplete, name);
+                let deref = quote!( let #nested: &#box_ty = #elm; );
+                ElmTokens {
+                    ser_stck: quote!( { #deref #ser_stck } ),
+                    ser_heap: quote!( { #deref #ser_heap } ),
+                    des: quote!( ::std::boxed::Box::new(#des) ),
+                    size,
+                    len: len.map(|len| quote!( { #deref #len } )),
+                }
+            }
+            FieldType::VecBytes { vec_ty } | FieldType::VecNumerics { vec_ty } | FieldType::VecStructs { vec_ty } => {
+                let deplete = match deplete {
+                    Some(deplete) => deplete,
+                    None => panic!(
+                        "{name} Vec<T> nested inside of an array, tuple, map, set or another Vec is not supported because its length is not part of the byte stream, use LenPrefixedBytes<L> or a struct with a #[byteserde(count( .. ))] Vec member instead"
+                    ),
+                };
+                if matches!(&vec_ty, Type::Path(TypePath { path, .. }) if path.is_ident("u8")) {
+                    let (ser, des, len) = match deplete {
+                        Deplete::Size(size) => (
+                            quote!( ser.serialize_bytes_slice(&#elm[..(#size) as usize])?; ),
+                            quote!( des.deserialize_bytes_slice( (#size) as usize )?.to_vec() ),
+                            quote!( ((#size) as usize) ),
+                        ),
+                        Deplete::NotSet => (
+                            quote!( ser.serialize_bytes_slice(&#elm[..])?; ),
+                            quote!( des.deserialize_bytes_slice_remaining().to_vec() ),
+                            quote!( #elm.len() ),
+                        ),
+                    };
+                    return ElmTokens {
+                        ser_stck: ser.clone(),
+                        ser_heap: ser,
+                        des,
+                        size: None,
+                        len: Some(len),
+                    };
+                }
+                let vec = &Ident::new(&format!("__vec{depth}"), Span::call_site());
+                let inner = elm_tokens(&vec_ty, nested, endian, nested_call, depth + 1, None, name);
+                let elm_len = inner.len_or_size();
+                let ElmTokens { ser_stck, ser_heap, des, .. } = inner;
+                let (take, des) = match deplete {
+                    Deplete::Size(size) => (
+                        quote!( .take( (#size) as usize ) ),
+                        quote!( { let mut #vec = ::std::vec::Vec::new(); for _ in 0..(#size) as usize { #vec.push(#des); } #vec } ),
+                    ),
+                    Deplete::NotSet => (quote!(), quote!( { let mut #vec = ::std::vec::Vec::new(); while !des.is_empty() { #vec.push(#des); } #vec } )),
+                };
+                ElmTokens {
+                    ser_stck: quote!( for #nested in #elm.iter()#take { #ser_stck } ),
+                    ser_heap: quote!( for #nested in #elm.iter()#take { #ser_heap } ),
+                    des,
+                    size: None,
+                    len: Some(quote!( #elm.iter()#take.map(|#nested| #elm_len).sum::<usize>() )),
+                }
+            }
+            FieldType::OptionStructs { .. } => panic!(
+                "{name} Option<T> nested inside of an array, tuple, Box, Vec, map, set or another Option is not supported, use an Option member with #[byteserde(when = ..)] or #[byteserde(presence = \"flag\")] instead"
+            ),
+            _ => struct_elm(),
+        },
+        _ => struct_elm(),
+    }
+}
+
 fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member: &MemberIdent, option: &FieldType) -> FldSerDesTokens {
     let deplete = deplete_attr(&fld.attrs);
     let replace = replace_attr(&fld.attrs);
+    if let Expect::Set(_) = expect_attr(&fld.attrs) {
+        panic!("{}.{} #[byteserde(expect( .. ))] is not supported on Vec members", ast.ident, var_name);
+    }
     let endian = endian_attr(&ast.attrs, &fld.attrs);
     let ser_endian_method_xx = ser_endian_method_xx(&endian);
     let des_endian_method_xx = des_endian_method_xx(&endian);
@@ -305,7 +1639,7 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
     };
 
     let ser_repl = match replace {
-        Replace::Set(ref value) => quote!( let #var_name = &#value; ),
+        Replace::Set(ref value) => quote!( let #var_name: &#ty = &#value; ),
         Replace::NotSet => quote!(),
     };
 
@@ -336,7 +1670,28 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
         Deplete::Size(ref size) => quote!( #size ),
         Deplete::NotSet => quote!( #member_name.len() ),
     };
-    let ser_uses_xxx = |byte_serialize_xxx: &Ident| match option {
+    // elements other than numerics, ex: structs, arrays, tuples, Box<T>
+    let (elm_ser_stck, elm_ser_heap, elm_des, elm_len) = match option {
+        FieldType::VecStructs { vec_ty } => {
+            let fld_name = match member {
+                MemberIdent::Named(fld_name) => quote!( #fld_name ),
+                MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+            };
+            let elm = elm_tokens(
+                vec_ty,
+                &Ident::new("__elm", Span::call_site()),
+                &endian,
+                Nested::of(ast, fld),
+                0,
+                None,
+                &format!("{struct_name}.{fld_name}"),
+            );
+            let elm_len = elm.len_or_size();
+            (elm.ser_stck, elm.ser_heap, elm.des, elm_len)
+        }
+        _ => (quote!(), quote!(), quote!(), quote!()),
+    };
+    let ser_uses_xxx = |elm_ser: &TokenStream| match option {
         FieldType::VecBytes { .. } => {
             quote!( #assert_vec_len_gt_then_deplete; ser.serialize_bytes_slice(&#var_name[..#vec_deplete_len])?; )
         }
@@ -344,14 +1699,14 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
             quote!( #assert_vec_len_gt_then_deplete; for (idx, n) in #var_name.iter().enumerate() { if idx >= #vec_deplete_len {break;} ser.#ser_endian_method_xx(*n)?; })
         }
         FieldType::VecStructs { .. } => {
-            quote!( #assert_vec_len_gt_then_deplete; for (idx, n) in #var_name.iter().enumerate() { if idx >= #vec_deplete_len {break;} n.#byte_serialize_xxx(ser)?; })
+            quote!( #assert_vec_len_gt_then_deplete; for (idx, __elm) in #var_name.iter().enumerate() { if idx >= #vec_deplete_len {break;} #elm_ser })
         }
         _ => panic!("this method should only be called with Vec[Bytes|Numerics|Structs] types"),
     };
 
     let des_vars_byte = match deplete {
         Deplete::Size(ref size) => {
-            quote!( let #var_name: #ty = des.deserialize_take::<#ty>( (#size) as usize )?.into(); )
+            quote!( let #var_name: #ty = des.deserialize_bytes_slice( (#size) as usize )?.into(); )
         }
         Deplete::NotSet => {
             quote!( let #var_name: #ty = des.deserialize_bytes_slice_remaining().into(); )
@@ -367,10 +1722,10 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
     };
     let des_vars_other = match deplete {
         Deplete::Size(ref size) => {
-            quote!( let mut #var_name: #ty = vec![]; for _ in 0..#size { #var_name.push(des.deserialize()?); })
+            quote!( let mut #var_name: #ty = vec![]; for _ in 0..#size { #var_name.push(#elm_des); })
         }
         Deplete::NotSet => {
-            quote!( let mut #var_name: #ty = vec![]; while des.is_empty() == false { #var_name.push(des.deserialize()?); })
+            quote!( let mut #var_name: #ty = vec![]; while des.is_empty() == false { #var_name.push(#elm_des); })
         }
     };
     let des_vars_xxx = match option {
@@ -379,30 +1734,57 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
         FieldType::VecStructs { .. } => des_vars_other,
         _ => panic!("this method should only be called with Vec types"),
     };
+    // explicit element count or byte budget, the later runs the element loop inside a bounded deserializer
+    let des_vars_xxx = match (vec_len_attr(&fld.attrs), option) {
+        (VecLen::NotSet, _) => des_vars_xxx,
+        (VecLen::Count(len) | VecLen::ByteLen(len), FieldType::VecBytes { .. }) => {
+            quote!( let #var_name: #ty = des.deserialize_bytes_slice( (#len) as usize )?.to_vec(); )
+        }
+        (VecLen::Count(count), FieldType::VecNumerics { .. }) => {
+            quote!( let mut #var_name: #ty = vec![]; for _ in 0..(#count) as usize { #var_name.push(des.#des_endian_method_xx()?); })
+        }
+        (VecLen::Count(count), _) => {
+            quote!( let mut #var_name: #ty = vec![]; for _ in 0..(#count) as usize { #var_name.push(#elm_des); })
+        }
+        (VecLen::ByteLen(byte_len), FieldType::VecNumerics { .. }) => quote!(
+            let #var_name: #ty = {
+                let des = &mut des.deserialize_bounded( (#byte_len) as usize )?;
+                let mut #var_name: #ty = vec![];
+                while !des.is_empty() { #var_name.push(des.#des_endian_method_xx()?); }
+                #var_name
+            };
+        ),
+        (VecLen::ByteLen(byte_len), _) => quote!(
+            let #var_name: #ty = {
+                let des = &mut des.deserialize_bounded( (#byte_len) as usize )?;
+                let mut #var_name: #ty = vec![];
+                while !des.is_empty() { #var_name.push(#elm_des); }
+                #var_name
+            };
+        ),
+    };
 
     let len = match option {
         FieldType::VecBytes { vec_ty } | FieldType::VecNumerics { vec_ty } => {
-            quote!( (::std::mem::size_of::<#vec_ty>() * #vec_deplete_len) )
+            let elm_size = numeric_size_of(vec_ty);
+            quote!( (#elm_size * #vec_deplete_len) )
         }
         FieldType::VecStructs { .. } => match replace {
             Replace::Set(ref value) => {
-                quote!( ({ let mut len = 0; for (idx, e) in #value.iter().enumerate() { if idx >= #vec_deplete_len {break} len += e.byte_len(); } len }) )
+                quote!( ({ let mut len = 0; for (idx, __elm) in #value.iter().enumerate() { if idx >= #vec_deplete_len {break} len += #elm_len; } len }) )
             }
             Replace::NotSet => {
-                quote!( ({ let mut len = 0; for (idx, e) in #member_name.iter().enumerate() { if idx >= #vec_deplete_len {break} len += e.byte_len(); } len }) )
+                quote!( ({ let mut len = 0; for (idx, __elm) in #member_name.iter().enumerate() { if idx >= #vec_deplete_len {break} len += #elm_len; } len }) )
             }
         },
         _ => panic!("this method should only be called ArrayBytes, ArrayNumerics, ArrayStructs types"),
     };
-    let size_error: Vec<String> = vec![format!(
-        "trait ByteSerializedLenOf can't be implemented for struct {} because it has a member {} of Vec type whose size is not know at compile time",
-        &ast.ident, member_name
-    )];
+    let size_error: Vec<String> = size_unknown_error(&ast.ident, &member_name, " of Vec type");
     FldSerDesTokens {
         ser_vars,
         ser_repl,
-        ser_uses_stck: ser_uses_xxx(&Ident::new("byte_serialize_stack", Span::call_site())),
-        ser_uses_heap: ser_uses_xxx(&Ident::new("byte_serialize_heap", Span::call_site())),
+        ser_uses_stck: ser_uses_xxx(&elm_ser_stck),
+        ser_uses_heap: ser_uses_xxx(&elm_ser_heap),
         des_vars: des_vars_xxx,
         des_peeked: quote!(), // does not apply here
         des_uses: quote!( #var_name, ),
@@ -412,9 +1794,83 @@ fn setup_vec(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member
         len_of: len,
     }
 }
-fn setup_struct(fld: &Field, var_name: &Ident, ty: &Type, member: &MemberIdent) -> FldSerDesTokens {
+/// member level explicit `endian` of a struct member or container of structs is set on the serializer & deserializer while the member is processed,
+/// so that its members with `#[byteserde(endian = "runtime")]`, ex: `#[byteserde(transparent)]` newtypes, use it, see [struct_endian] for struct level
+fn inherit_endian(fld: &Field) -> bool {
+    inherited_endian(&endian_attr(&[], &fld.attrs)).is_some()
+}
+/// struct level explicit `endian` set on the serializer & deserializer for the whole struct so that nested members with
+/// `#[byteserde(endian = "runtime")]`, ex: `#[byteserde(transparent)]` newtypes, inherit it
+pub fn struct_endian(ast: &DeriveInput) -> Option<TokenStream> {
+    match ast.data {
+        Data::Struct(_) => inherited_endian(&endian_attr(&ast.attrs, &[])),
+        _ => None,
+    }
+}
+fn inherited_endian(endian: &Endian) -> Option<TokenStream> {
+    match endian {
+        Endian::Big => Some(quote!(::byteserde::prelude::Endian::Big)),
+        Endian::Lit => Some(quote!(::byteserde::prelude::Endian::Little)),
+        Endian::Native => Some(quote!(::byteserde::prelude::Endian::Native)),
+        Endian::Runtime | Endian::NotSet => None,
+    }
+}
+/// how a nested struct like member or element is called, see [ser_nested] & [des_nested]
+#[derive(Clone, Copy, Default)]
+struct Nested {
+    /// member level explicit `endian` is set on the serializer & deserializer, see [inherit_endian]
+    inherit: bool,
+    /// struct level `#[byteserde(ctx = ..)]` is passed through using `_with` variants of the traits
+    ctx: bool,
+}
+impl Nested {
+    fn of(ast: &DeriveInput, fld: &Field) -> Self {
+        Nested {
+            inherit: inherit_endian(fld),
+            ctx: ctx_attr(&ast.attrs).is_some(),
+        }
+    }
+}
+/// statement serializing a nested struct `elm` using `byte_serialize_xxx`, ex: `byte_serialize_stack`
+fn ser_nested(endian: &Endian, nested: Nested, elm: TokenStream, byte_serialize_xxx: &str) -> TokenStream {
+    let call = match nested.ctx {
+        true => {
+            let byte_serialize_xxx_with = Ident::new(&format!("{byte_serialize_xxx}_with"), Span::call_site());
+            quote!( #elm.#byte_serialize_xxx_with(ser, ctx) )
+        }
+        false => {
+            let byte_serialize_xxx = Ident::new(byte_serialize_xxx, Span::call_site());
+            quote!( #elm.#byte_serialize_xxx(ser) )
+        }
+    };
+    match inherited_endian(endian) {
+        Some(endian) if nested.inherit => quote!( { let __endian = ser.endian(); ser.set_endian(#endian); let __res = #call; ser.set_endian(__endian); __res?; } ),
+        _ => quote!( #call?; ),
+    }
+}
+/// expression deserializing a nested struct, depleting exactly `take` bytes when set
+fn des_nested(endian: &Endian, nested: Nested, take: Option<TokenStream>) -> TokenStream {
+    let call = match (take, nested.ctx) {
+        (Some(len), true) => quote!( des.deserialize_take_with( (#len) as usize, ctx ) ),
+        (Some(len), false) => quote!( des.deserialize_take( (#len) as usize ) ),
+        (None, true) => quote!(des.deserialize_with(ctx)),
+        (None, false) => quote!(des.deserialize()),
+    };
+    match inherited_endian(endian) {
+        Some(endian) if nested.inherit => quote!( { let __endian = des.endian(); des.set_endian(#endian); let __res = #call; des.set_endian(__endian); __res? } ),
+        _ => quote!( #call? ),
+    }
+}
+
+fn setup_struct(ast: &DeriveInput, fld: &Field, var_name: &Ident, ty: &Type, member: &MemberIdent) -> FldSerDesTokens {
+    if !matches!(vec_len_attr(&fld.attrs), VecLen::NotSet) {
+        panic!(
+            "{}.{} #[byteserde(count( .. ))] and #[byteserde(byte_len( .. ))] are only supported on Vec members, use #[byteserde(deplete( .. ))] instead",
+            ast.ident, var_name
+        );
+    }
     let length = deplete_attr(&fld.attrs);
-    let replace = replace_attr(&fld.attrs);
+    let replace = replace_or_expect_attr(&fld.attrs);
     let ser_vars = match member {
         MemberIdent::Named(_) => quote! { let #var_name = &self.#var_name; },            // let #var_name = &self.#var_name;
         MemberIdent::Unnamed(fld_index) => quote! { let #var_name = &self.#fld_index; }, // let #var_name = &self.#fld_index;
@@ -423,12 +1879,16 @@ fn setup_struct(fld: &Field, var_name: &Ident, ty: &Type, member: &MemberIdent)
         Replace::Set(value) => quote!( let #var_name: &#ty = &#value; ),
         Replace::NotSet => quote!(),
     };
-    let des_vars = match length {
-        Deplete::Size(len) => {
-            quote!( let #var_name: #ty = des.deserialize_take( (#len) as usize )?; )
-        }
-        Deplete::NotSet => quote!( let #var_name: #ty = des.deserialize()?; ),
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let nested = Nested::of(ast, fld);
+    let des = match length {
+        Deplete::Size(len) => des_nested(&endian, nested, Some(quote!(#len))),
+        Deplete::NotSet => des_nested(&endian, nested, None),
     };
+    let des_vars = quote!( let #var_name: #ty = #des; );
+    let des_vars = des_expect(ast, fld, var_name, member, des_vars, |value| quote!( let __expected: #ty = #value; ));
+    let ser_uses_stck = ser_nested(&endian, nested, quote!(#var_name), "byte_serialize_stack");
+    let ser_uses_heap = ser_nested(&endian, nested, quote!(#var_name), "byte_serialize_heap");
 
     let len_of = match member {
         MemberIdent::Named(_) => quote! { self.#var_name.byte_len() },            // let #var_name = &self.#var_name;
@@ -437,13 +1897,13 @@ fn setup_struct(fld: &Field, var_name: &Ident, ty: &Type, member: &MemberIdent)
     FldSerDesTokens {
         ser_vars,
         ser_repl,
-        ser_uses_stck: quote!( #var_name.byte_serialize_stack(ser)?; ),
-        ser_uses_heap: quote!( #var_name.byte_serialize_heap(ser)?; ),
+        ser_uses_stck,
+        ser_uses_heap,
         des_vars,
         des_peeked: quote!(), // does not apply here
         des_uses: quote!( #var_name, ),
         des_errors: vec![],
-        size_of: quote!( #ty.byte_size() ),
+        size_of: quote!( <#ty as ::byteserde::size::ByteSerializedSizeOf>::BYTE_SIZE ),
         size_errors: vec![],
         // len_of: quote!( self.#var_name.byte_len() ),
         // len_of: quote!( #var_name.byte_len() ),
@@ -451,6 +1911,45 @@ fn setup_struct(fld: &Field, var_name: &Ident, ty: &Type, member: &MemberIdent)
     }
 }
 
+/// Tuple & `Box<T>` members, see [elm_tokens] for supported shapes, `#[byteserde(deplete( .. ))]` applies to the innermost `Vec`, `String` or struct like type
+fn setup_composite(ast: &DeriveInput, fld: &Field, ty: &Type, var_name: &Ident, member: &MemberIdent) -> FldSerDesTokens {
+    let struct_name = &ast.ident;
+    let fld_name = match member {
+        MemberIdent::Named(fld_name) => quote!( #fld_name ),
+        MemberIdent::Unnamed(fld_index) => quote!( #fld_index ),
+    };
+    if !matches!(vec_len_attr(&fld.attrs), VecLen::NotSet) {
+        panic!("{struct_name}.{fld_name} #[byteserde(count( .. ))] and #[byteserde(byte_len( .. ))] are only supported on Vec members, use #[byteserde(deplete( .. ))] instead");
+    }
+    let endian = endian_attr(&ast.attrs, &fld.attrs);
+    let elm = elm_tokens(ty, var_name, &endian, Nested::of(ast, fld), 0, Some(&deplete_attr(&fld.attrs)), &format!("{struct_name}.{fld_name}"));
+
+    let ser_repl = match replace_or_expect_attr(&fld.attrs) {
+        Replace::Set(value) => quote!( let #var_name: &#ty = &#value; ),
+        Replace::NotSet => quote!(),
+    };
+    let des = &elm.des;
+    let des_vars = des_expect(ast, fld, var_name, member, quote!( let #var_name: #ty = #des; ), |value| quote!( let __expected: #ty = #value; ));
+    let len = elm.len_or_size();
+    let (size_of, size_errors) = match elm.size {
+        Some(size) => (size, vec![]),
+        None => (quote!(0), size_unknown_error(struct_name, &fld_name, "")),
+    };
+    FldSerDesTokens {
+        ser_vars: quote!( let #var_name: &#ty = &self.#fld_name; ),
+        ser_repl,
+        ser_uses_stck: elm.ser_stck,
+        ser_uses_heap: elm.ser_heap,
+        des_vars,
+        des_peeked: quote!(), // does not apply here
+        des_uses: quote!( #var_name, ),
+        des_errors: vec![],
+        size_of,
+        size_errors,
+        len_of: quote!( ({ let #var_name: &#ty = &self.#fld_name; #len }) ),
+    }
+}
+
 fn setup_option(ast: &DeriveInput, fld: &Field, fld_ty: &Type, var_name: &Ident, member: &MemberIdent, option: &FieldType) -> FldSerDesTokens {
     let mut des_errors = vec![];
     let struct_name = &ast.ident;
@@ -459,6 +1958,9 @@ fn setup_option(ast: &DeriveInput, fld: &Field, fld_ty: &Type, var_name: &Ident,
         MemberIdent::Unnamed(fld_idx) => quote!(#fld_idx),
     };
     let replace = replace_attr(&fld.attrs);
+    if let Expect::Set(_) = expect_attr(&fld.attrs) {
+        panic!("{}.{} #[byteserde(expect( .. ))] is not supported on Option members", ast.ident, var_name);
+    }
     let eq = match eq_attr(&fld.attrs) {
         PeekEq::Set(value) => quote!(#value),
         PeekEq::NotSet => {
@@ -480,16 +1982,27 @@ fn setup_option(ast: &DeriveInput, fld: &Field, fld_ty: &Type, var_name: &Ident,
         Replace::NotSet => quote!(),
     };
 
-    let ser_uses_xxx = |byte_serialize_xxx: &Ident| match option {
-        FieldType::OptionStructs { .. } => {
-            quote!( match #var_name { Some(v) => v.#byte_serialize_xxx(ser)?, None => {}, } )
+    let some = match option {
+        FieldType::OptionStructs { opt_ty } => {
+            let endian = endian_attr(&ast.attrs, &fld.attrs);
+            elm_tokens(
+                opt_ty,
+                &Ident::new("__some", Span::call_site()),
+                &endian,
+                Nested::of(ast, fld),
+                0,
+                Some(&deplete_attr(&fld.attrs)),
+                &format!("{struct_name}.{fld_name}"),
+            )
         }
         _ => panic!("this method should only be called with OptionStructs types"),
     };
+    let ser_uses_xxx = |ser_some: &TokenStream| quote!( match #var_name { Some(__some) => { #ser_some } None => {} } );
+    let (des_some, len_some) = (&some.des, some.len_or_size());
 
     // TODO does it make sense to default Option size to Some size?
     let size_of = match option {
-        FieldType::OptionStructs { opt_ty } => quote!( Option::<#opt_ty>::byte_size() ),
+        FieldType::OptionStructs { opt_ty } => quote!( <Option<#opt_ty> as ::byteserde::size::ByteSerializedSizeOf>::BYTE_SIZE ),
         _ => panic!("this method should only be called with Option types"),
     };
 
@@ -497,15 +2010,15 @@ fn setup_option(ast: &DeriveInput, fld: &Field, fld_ty: &Type, var_name: &Ident,
     FldSerDesTokens {
         ser_vars,
         ser_repl,
-        ser_uses_stck: ser_uses_xxx(&Ident::new("byte_serialize_stack", Span::call_site())),
-        ser_uses_heap: ser_uses_xxx(&Ident::new("byte_serialize_heap", Span::call_site())),
+        ser_uses_stck: ser_uses_xxx(&some.ser_stck),
+        ser_uses_heap: ser_uses_xxx(&some.ser_heap),
         des_vars: quote!( let mut #var_name: #fld_ty = None; ),
-        des_peeked: quote!(if __peeked == #eq { #var_name = Some(des.deserialize()?); continue; }),
+        des_peeked: quote!(if __peeked == #eq { #var_name = Some(#des_some); continue; }),
         des_uses: quote!( #var_name, ),
         des_errors,
         size_of,
         size_errors: vec![],
-        len_of: quote!( self.#var_name.byte_len() ),
+        len_of: quote!( (match &self.#fld_name { Some(__some) => #len_some, None => 0 }) ),
     }
 }
 
@@ -524,7 +2037,6 @@ enum FieldType<'a> {
     ArrBytes {
         arr_ty: &'a Type,
         len: &'a Expr,
-        signed: bool,
     },
     ArrNumerics {
         arr_ty: &'a Type,
@@ -534,40 +2046,77 @@ enum FieldType<'a> {
         arr_ty: &'a Type,
         len: &'a Expr,
     },
+    /// nested arrays, ex: `[[u16; 4]; 8]` where `arr_ty` is `[u16; 4]`
+    ArrArrays {
+        arr_ty: &'a Type,
+        len: &'a Expr,
+    },
     VecBytes {
         vec_ty: Type,
     },
     VecNumerics {
         vec_ty: Type,
     },
-    #[allow(dead_code)] // TODO might be used later for now disable warning
     VecStructs {
         vec_ty: Type,
     },
     OptionStructs {
         opt_ty: Type,
     },
+    /// tuples & `Box<T>`, ex: `(u16, [u8; 4])`, `Box<Vec<u8>>`
+    Composite {
+        ty: &'a Type,
+    },
+    /// `HashMap<K, V>`, `BTreeMap<K, V>`, `IndexMap<K, V>` & their set counterparts where `val_ty` is `None`
+    Map {
+        order: MapOrder,
+        key_ty: &'a Type,
+        val_ty: Option<&'a Type>,
+    },
+}
+/// Order in which map & set entries are serialized
+#[derive(Debug, Clone, Copy)]
+enum MapOrder {
+    /// `BTreeMap` & `BTreeSet` iteration order
+    Sorted,
+    /// `IndexMap` & `IndexSet` iteration order
+    Insertion,
+    /// `HashMap` & `HashSet` entries are sorted by key prior to serialization, hence `K: Ord`
+    Hashed,
 }
 
-fn map_field_type(ty: &Type) -> FieldType {
+fn map_field_type(ty: &Type) -> FieldType<'_> {
     // eprintln!("\tmap_field_type: {:?}", ty);
     match ty {
         Type::Path(TypePath { path, .. }) => path_2_byte_numeric_vec_struct(path, ty),
         Type::Array(TypeArray { elem: arr_ty, len, .. }) => match arr_ty.as_ref() {
             Type::Path(TypePath { path, .. }) => match path_2_byte_numeric_vec_struct(path, arr_ty) {
-                FieldType::Byte { signed, .. } => FieldType::ArrBytes { arr_ty, len, signed },
+                FieldType::Byte { .. } => FieldType::ArrBytes { arr_ty, len },
                 FieldType::Numeric { .. } => FieldType::ArrNumerics { arr_ty, len },
-                FieldType::Struct { .. } => FieldType::ArrStructs { arr_ty, len },
-                _ => FieldType::Struct { ty: arr_ty },
+                _ => FieldType::ArrStructs { arr_ty, len },
             },
-            _ => FieldType::Struct { ty: arr_ty },
+            Type::Array(..) => FieldType::ArrArrays { arr_ty, len },
+            _ => FieldType::ArrStructs { arr_ty, len },
         },
         // for some reason when using macro_rules! to create a tuple struct ex: struct Me(u32) the type of the tuple comes in the TypeGroup instead of TypePath so we need to handle it here
         Type::Group(TypeGroup { elem, .. }) => map_field_type(elem),
+        Type::Tuple(..) => FieldType::Composite { ty },
         _ => FieldType::Struct { ty },
     }
 }
 
+/// `U24`, `I24`, `U40`, etc. numerics from `::byteserde::utils::numerics::odd_width` whose size in the byte stream is not the same as their `mem::size_of`
+const ODD_WIDTHS: [&str; 8] = ["U24", "I24", "U40", "I40", "U48", "I48", "U56", "I56"];
+fn is_odd_width(path: &Path) -> bool {
+    ODD_WIDTHS.iter().any(|odd| path.is_ident(odd))
+}
+/// Number of bytes a [FieldType::Numeric] or [FieldType::Byte] type occupies in the byte stream
+fn numeric_size_of(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Path(TypePath { path, .. }) if is_odd_width(path) => quote!( <#ty as ::byteserde::size::ByteSerializedSizeOf>::BYTE_SIZE ),
+        _ => quote!( ::std::mem::size_of::<#ty>() ),
+    }
+}
 fn path_2_byte_numeric_vec_struct<'a>(path: &'a Path, ty: &'a Type) -> FieldType<'a> {
     // byte
     if path.is_ident("u8") {
@@ -578,7 +2127,8 @@ fn path_2_byte_numeric_vec_struct<'a>(path: &'a Path, ty: &'a Type) -> FieldType
     }
 
     // all non byte numerics
-    if path.is_ident("i16")
+    if is_odd_width(path)
+        || path.is_ident("i16")
         || path.is_ident("u16")
         || path.is_ident("i32")
         || path.is_ident("u32")
@@ -593,40 +2143,79 @@ fn path_2_byte_numeric_vec_struct<'a>(path: &'a Path, ty: &'a Type) -> FieldType
     }
 
     // Vec
-    if path.segments.len() == 1 && path.segments[0].ident == "Vec" {
-        let vec_args = &path.segments[0].arguments;
-        if let PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) = vec_args {
-            if let GenericArgument::Type(Type::Path(path, ..)) = &args[0] {
-                let vec_ty = Type::Path(path.clone());
-                return match path_2_byte_numeric_vec_struct(&path.path, &vec_ty) {
-                    FieldType::Numeric { .. } => FieldType::VecNumerics { vec_ty },
-                    FieldType::Byte { .. } => FieldType::VecBytes { vec_ty },
-                    _ => FieldType::VecStructs { vec_ty },
-                };
-            }
+    if let Some(vec_ty) = generic_arg_of(path, "Vec") {
+        let elm_type = match vec_ty {
+            Type::Path(TypePath { path, .. }) => path_2_byte_numeric_vec_struct(path, vec_ty),
+            _ => FieldType::Struct { ty: vec_ty },
+        };
+        return match elm_type {
+            FieldType::Numeric { .. } => FieldType::VecNumerics { vec_ty: vec_ty.clone() },
+            FieldType::Byte { .. } => FieldType::VecBytes { vec_ty: vec_ty.clone() },
+            _ => FieldType::VecStructs { vec_ty: vec_ty.clone() },
         };
     }
     // Option
-    if path.segments.len() == 1 && path.segments[0].ident == "Option" {
-        let opt_args = &path.segments[0].arguments;
-        if let PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) = opt_args {
-            if let GenericArgument::Type(Type::Path(path, ..)) = &args[0] {
-                // eprintln!("\t\tOption: {:?}", path);
-                let opt_ty = Type::Path(path.clone());
-                return match path_2_byte_numeric_vec_struct(&path.path, &opt_ty) {
-                    FieldType::Struct { .. } => FieldType::OptionStructs { opt_ty },
-                    // FieldType::Byte { .. } => FieldType::OptionBytes { opt_ty } ,
-                    // FieldType::Numeric { .. } => FieldType::OptionNumerics { opt_ty },
-                    _ => panic!("Option of Byte & Numerics are not supported only of other struct types. Ex: Option<SomeStruct>"),
-                };
-            }
+    if let Some(opt_ty) = generic_arg_of(path, "Option") {
+        return match map_field_type(opt_ty) {
+            FieldType::Byte { .. } | FieldType::Numeric { .. } => panic!("Option of Byte & Numerics are not supported only of other struct types. Ex: Option<SomeStruct>"),
+            _ => FieldType::OptionStructs { opt_ty: opt_ty.clone() },
+        };
+    }
+    // Box
+    if generic_arg_of(path, "Box").is_some() {
+        return FieldType::Composite { ty };
+    }
+    // maps & sets, ex: std::collections::HashMap<K, V>
+    if let Some(segment) = path.segments.last() {
+        let (order, is_set) = match segment.ident.to_string().as_str() {
+            "BTreeMap" => (MapOrder::Sorted, false),
+            "BTreeSet" => (MapOrder::Sorted, true),
+            "IndexMap" => (MapOrder::Insertion, false),
+            "IndexSet" => (MapOrder::Insertion, true),
+            "HashMap" => (MapOrder::Hashed, false),
+            "HashSet" => (MapOrder::Hashed, true),
+            _ => return FieldType::Struct { ty },
+        };
+        let args = match &segment.arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        };
+        // optional trailing hasher type is ignored
+        return match (is_set, args.as_slice()) {
+            (false, [key_ty, val_ty, ..]) => FieldType::Map { order, key_ty, val_ty: Some(val_ty) },
+            (true, [key_ty, ..]) => FieldType::Map { order, key_ty, val_ty: None },
+            _ => FieldType::Struct { ty },
         };
     }
 
     FieldType::Struct { ty }
 }
+/// `T` of a single segment path `container<T>`, ex: `Vec<T>`, `Option<T>`, `Box<T>`
+fn generic_arg_of<'a>(path: &'a Path, container: &str) -> Option<&'a Type> {
+    if path.segments.len() != 1 || path.segments[0].ident != container {
+        return None;
+    }
+    match &path.segments[0].arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => match args.first() {
+            Some(GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
-pub fn get_generics(generics: &Generics) -> (TokenStream, TokenStream, TokenStream) {
+/// Returns `impl` generics declaration, type alias & `where` clause. Every type parameter used by a serialized member is bound in the `where` clause
+/// by the trait returned from `bound(param, with_ctx)`, where `with_ctx` is set for `#[byteserde(with_ctx)]` members, unless struct level
+/// `#[byteserde(bound = "..")]` is set in which case its predicates are used instead. Members which are skipped, `PhantomData` or use
+/// `#[byteserde(with = ..)]` & `#[byteserde(as = ..)]` don't require a bound.
+pub fn get_generics(ast: &DeriveInput, bound: impl Fn(&Ident) -> TokenStream) -> (TokenStream, TokenStream, TokenStream) {
+    let generics = &ast.generics;
     let type_alias = generics
         .params
         .iter()
@@ -645,12 +2234,61 @@ pub fn get_generics(generics: &Generics) -> (TokenStream, TokenStream, TokenStre
             // param
         })
         .collect::<Vec<_>>();
-    let where_clause = match &generics.where_clause {
-        Some(where_clause) => quote! ( #where_clause ),
-        None => quote!(),
+    let mut where_clause = generics.where_clause.clone().unwrap_or_else(|| parse_quote!(where));
+    match bound_attr(&ast.attrs) {
+        Some(predicates) => where_clause.predicates.extend(predicates),
+        None => {
+            for param in bounded_type_params(ast) {
+                let bound = bound(param);
+                where_clause.predicates.push(parse_quote!( #param: #bound ));
+            }
+        }
+    }
+    let where_clause = match where_clause.predicates.is_empty() {
+        true => quote!(),
+        false => quote! ( #where_clause ),
     };
     match generics.params.len() {
         0 => (quote!(), quote!(), where_clause),
         _ => (quote! ( #generics ), quote! ( < #(#type_alias),* > ), where_clause),
     }
 }
+/// type parameters used by members which are present in the byte stream
+fn bounded_type_params(ast: &DeriveInput) -> Vec<&Ident> {
+    let flds = match &ast.data {
+        Data::Struct(data) => data.fields.iter().collect::<Vec<_>>(),
+        Data::Enum(data) => data.variants.iter().flat_map(|variant| variant.fields.iter()).collect::<Vec<_>>(),
+        Data::Union(_) => vec![],
+    };
+    let flds = flds
+        .into_iter()
+        .filter(|fld| matches!(skip_attr(&fld.attrs), Skip::NotSet) && !is_phantom_data(&fld.ty) && with_attr(&fld.attrs).is_none() && as_attr(&fld.attrs).is_none())
+        .collect::<Vec<_>>();
+    ast.generics
+        .type_params()
+        .filter(|param| flds.iter().any(|fld| mentions_type_param(&fld.ty, &param.ident)))
+        .map(|param| &param.ident)
+        .collect()
+}
+fn mentions_type_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(TypePath { qself, path }) => {
+            qself.as_ref().map_or(false, |qself| mentions_type_param(&qself.ty, param))
+                || (path.leading_colon.is_none() && path.segments.first().map_or(false, |seg| seg.ident == *param))
+                || path.segments.iter().any(|seg| match &seg.arguments {
+                    PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => args.iter().any(|arg| match arg {
+                        GenericArgument::Type(ty) => mentions_type_param(ty, param),
+                        _ => false,
+                    }),
+                    _ => false,
+                })
+        }
+        Type::Array(TypeArray { elem, .. })
+        | Type::Group(TypeGroup { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. })
+        | Type::Reference(TypeReference { elem, .. }) => mentions_type_param(elem, param),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter().any(|elem| mentions_type_param(elem, param)),
+        _ => false,
+    }
+}
@@ -0,0 +1,34 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct BackfillMissingMember {
+    #[byteserde(backfill_len(of = body))]
+    len: u16,
+    data: Vec<u8>,
+}
+
+#[derive(ByteSerializeStack)]
+struct BackfillPrecedingMember {
+    data: Vec<u8>,
+    #[byteserde(backfill_len(of = data))]
+    len: u16,
+}
+
+#[derive(ByteSerializeStack)]
+struct BackfillWithReplace {
+    #[byteserde(backfill_len(of = data), replace(1))]
+    len: u16,
+    data: Vec<u8>,
+}
+
+#[derive(ByteSerializeStack)]
+struct BackfillArrayLen {
+    #[byteserde(backfill_len(of = data))]
+    len: [u8; 2],
+    data: Vec<u8>,
+}
+
+fn main() {}
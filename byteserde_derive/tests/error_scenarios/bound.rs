@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+#[byteserde(bound = "T ByteSerializeStack")]
+struct BoundInvalid<T> {
+    field: T,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct ChecksumUnknownAlgorithm {
+    a: u8,
+    #[byteserde(checksum = "md5")]
+    crc: u32,
+}
+
+#[derive(ByteSerializeStack)]
+struct ChecksumInvalidOver {
+    a: u8,
+    #[byteserde(checksum = "crc32", over = "a..b")]
+    crc: u32,
+}
+
+#[derive(ByteSerializeStack)]
+struct ChecksumOverFollowingMember {
+    a: u8,
+    #[byteserde(checksum = "crc32", over = "b..self")]
+    crc: u32,
+    b: u8,
+}
+
+#[derive(ByteSerializeStack)]
+struct ChecksumFirstMember {
+    #[byteserde(checksum = "crc32")]
+    crc: u32,
+    a: u8,
+}
+
+#[derive(ByteSerializeStack)]
+struct ChecksumWrongType {
+    a: u8,
+    #[byteserde(checksum = "crc32")]
+    crc: u16,
+}
+
+#[derive(ByteSerializeStack)]
+struct ChecksumSigned {
+    a: u8,
+    #[byteserde(checksum = "mod256")]
+    crc: i8,
+}
+
+fn main() {}
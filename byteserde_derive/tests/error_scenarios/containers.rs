@@ -0,0 +1,35 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct Inner(u8);
+
+#[derive(ByteDeserializeSlice)]
+struct VecOfVec {
+    field: Vec<Vec<u8>>,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct TupleWithVec {
+    field: (u8, Vec<u16>),
+}
+
+#[derive(ByteDeserializeSlice)]
+struct VecOfOption {
+    field: Vec<Option<Inner>>,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct Unit {
+    field: (),
+}
+
+#[derive(ByteDeserializeSlice)]
+struct DepleteOnTuple {
+    #[byteserde(deplete(2))]
+    field: (u8, u8),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct CountAndByteLen {
+    a: u8,
+    #[byteserde(count(a), byte_len(a))]
+    b: Vec<u16>,
+}
+
+fn main() {}
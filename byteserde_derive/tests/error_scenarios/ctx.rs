@@ -0,0 +1,18 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct Inner {
+    a: u8,
+}
+
+#[derive(ByteSerializeStack)]
+#[byteserde(peek(0, 1), ctx = u8)]
+enum CtxOnEnum {
+    #[byteserde(eq(1_u8.to_ne_bytes()))]
+    A(Inner),
+}
+
+fn main() {}
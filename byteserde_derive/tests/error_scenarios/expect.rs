@@ -0,0 +1,18 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct ExpectWithReplace {
+    #[byteserde(expect(1), replace(2))]
+    a: u8,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct ExpectOnVec {
+    #[byteserde(expect(vec![1_u8]))]
+    a: Vec<u8>,
+}
+
+fn main() {}
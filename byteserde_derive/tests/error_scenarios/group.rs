@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct GroupWithInvalidCountType {
+    #[byteserde(group(block_len = "u16", count = "i32"))]
+    a: Vec<u8>,
+}
+
+fn main() {}
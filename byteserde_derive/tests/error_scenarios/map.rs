@@ -0,0 +1,31 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+use std::collections::BTreeMap;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct CountPrefixOnVec {
+    #[byteserde(count_prefix = "u16")]
+    field: Vec<u16>,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct CountPrefixAndCount {
+    #[byteserde(count_prefix = "u16")]
+    #[byteserde(count(2))]
+    field: BTreeMap<u8, u8>,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct CountPrefixNotNumeric {
+    #[byteserde(count_prefix = "i16")]
+    field: BTreeMap<u8, u8>,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct MapOfVec {
+    field: BTreeMap<u8, Vec<u8>>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+#[byteserde(pad_to = 8)]
+enum PadToEnum {
+    #[byteserde(eq(1_u8.to_be_bytes()))]
+    A(u8),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+#[byteserde(presence_map = "u8")]
+struct PresenceMapWithoutOptions {
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct DefaultWithoutSkip {
+    #[byteserde(default = 1)]
+    a: u8,
+}
+
+fn main() {}
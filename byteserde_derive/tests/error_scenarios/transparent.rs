@@ -0,0 +1,29 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+#[byteserde(transparent)]
+struct TransparentTwoMembers(u32, u16);
+
+#[derive(ByteDeserializeSlice)]
+struct AsNotNumeric {
+    #[byteserde(as = "String")]
+    field: u8,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct AsInvalidType {
+    #[byteserde(as = "u8;")]
+    field: u8,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct AsAndDeplete {
+    #[byteserde(as = "u16")]
+    #[byteserde(deplete(2))]
+    field: u8,
+}
+
+fn main() {}
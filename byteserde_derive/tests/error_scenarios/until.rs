@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct UntilOnNumeric {
+    #[byteserde(until = 0x00)]
+    a: u32,
+}
+
+fn main() {}
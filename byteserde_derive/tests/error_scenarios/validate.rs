@@ -0,0 +1,33 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+fn always_ok<T>(_: &T) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(ByteDeserializeSlice)]
+struct ValidateSerWithoutValidate {
+    #[byteserde(validate_ser = true)]
+    a: u8,
+}
+
+#[derive(ByteDeserializeSlice)]
+struct Inner(u8);
+
+#[derive(ByteDeserializeSlice)]
+#[byteserde(peek(0, 1))]
+struct ValidateOption {
+    #[byteserde(eq(&[1]), validate = always_ok)]
+    a: Option<Inner>,
+}
+
+#[derive(ByteDeserializeSlice)]
+#[byteserde(peek(0, 1), validate = always_ok)]
+enum ValidateEnum {
+    #[byteserde(eq(&[1]))]
+    A(Inner),
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteSerializeStack;
+
+#[derive(ByteSerializeStack)]
+struct VersionRangeInverted {
+    #[byteserde(since = 3, until_version = 2)]
+    a: u8,
+}
+
+#[derive(ByteSerializeStack)]
+struct VersionChecksumFrom {
+    a: u8,
+    #[byteserde(since = 2)]
+    b: u16,
+    #[byteserde(checksum = "crc16_ccitt", over = "b..self")]
+    crc: u16,
+}
+
+#[derive(ByteSerializeStack)]
+struct VersionChecksumStart {
+    #[byteserde(since = 2)]
+    a: u8,
+    #[byteserde(checksum = "crc16_ccitt", over = "start..self")]
+    crc: u16,
+}
+
+#[derive(ByteSerializeStack)]
+struct VersionBackfillLen {
+    #[byteserde(since = 2)]
+    #[byteserde(backfill_len(of = body))]
+    len: u16,
+    body: Vec<u8>,
+}
+
+fn main() {}
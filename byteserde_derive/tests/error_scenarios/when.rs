@@ -0,0 +1,13 @@
+#[allow(unused_imports)]
+use byteserde::prelude::*;
+
+use byteserde_derive::ByteDeserializeSlice;
+
+#[derive(ByteDeserializeSlice)]
+struct WhenNotOption {
+    a: u8,
+    #[byteserde(when = a == 1)]
+    b: u16,
+}
+
+fn main() {}
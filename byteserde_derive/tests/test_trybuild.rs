@@ -4,4 +4,21 @@ fn fail_use_cases() {
     t.compile_fail("tests/error_scenarios/size_of_vec.rs");
     t.compile_fail("tests/error_scenarios/union.rs");
     t.compile_fail("tests/error_scenarios/option.rs");
+    t.compile_fail("tests/error_scenarios/backfill_len.rs");
+    t.compile_fail("tests/error_scenarios/checksum.rs");
+    t.compile_fail("tests/error_scenarios/expect.rs");
+    t.compile_fail("tests/error_scenarios/validate.rs");
+    t.compile_fail("tests/error_scenarios/skip.rs");
+    t.compile_fail("tests/error_scenarios/pad_to.rs");
+    t.compile_fail("tests/error_scenarios/until.rs");
+    t.compile_fail("tests/error_scenarios/count.rs");
+    t.compile_fail("tests/error_scenarios/when.rs");
+    t.compile_fail("tests/error_scenarios/presence.rs");
+    t.compile_fail("tests/error_scenarios/group.rs");
+    t.compile_fail("tests/error_scenarios/version.rs");
+    t.compile_fail("tests/error_scenarios/ctx.rs");
+    t.compile_fail("tests/error_scenarios/containers.rs");
+    t.compile_fail("tests/error_scenarios/map.rs");
+    t.compile_fail("tests/error_scenarios/transparent.rs");
+    t.compile_fail("tests/error_scenarios/bound.rs");
 }
@@ -9,9 +9,7 @@ fn to_bytes_stack(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("byteserde::to_bytes_stack", |b| {
         b.iter(|| {
-            black_box({
-                let _: ([u8; 128], usize) = byteserde::prelude::to_bytes_stack(&inp).unwrap();
-            })
+            black_box::<([u8; 128], usize)>(byteserde::prelude::to_bytes_stack(&inp).unwrap());
         })
     });
 }
@@ -21,10 +19,8 @@ fn byte_serialize_heap(c: &mut Criterion) {
     let mut ser = ByteSerializerHeap::with_capacity(1024);
     c.bench_function("ser.clear(); inp.byte_serialize_heap", |b| {
         b.iter(|| {
-            black_box({
-                ser.clear();
-                let _ = inp.byte_serialize_heap(&mut ser).unwrap();
-            })
+            ser.clear();
+            black_box(inp.byte_serialize_heap(&mut ser)).unwrap();
         })
     });
 }
@@ -33,9 +29,7 @@ fn from_slice(c: &mut Criterion) {
     let ser: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
     c.bench_function("byteserde::from_slice", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = byteserde::prelude::from_slice(&ser.as_slice()).unwrap();
-            })
+            black_box::<Numbers>(byteserde::prelude::from_slice(ser.as_slice()).unwrap());
         })
     });
 }
@@ -46,9 +40,7 @@ fn from_bytes(c: &mut Criterion) {
     let bytes: Bytes = ser.as_slice().to_vec().into();
     c.bench_function("byteserde::from_bytes", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = byteserde::prelude::from_bytes(bytes.clone()).unwrap();
-            })
+            black_box::<Numbers>(byteserde::prelude::from_bytes(bytes.clone()).unwrap());
         })
     });
 }
@@ -10,10 +10,8 @@ fn reset_byte_serialize_stack(c: &mut Criterion) {
     let ser = &mut ByteSerializerStack::<128>::default();
     c.bench_function("byte_serialize_stack - reset ByteSerializerStack", |b| {
         b.iter(|| {
-            black_box({
-                ser.clear();
-                let _ = inp.byte_serialize_stack(ser).unwrap();
-            })
+            ser.clear();
+            black_box(inp.byte_serialize_stack(ser)).unwrap();
         })
     });
 }
@@ -22,9 +20,7 @@ fn new_byte_serialize_stack(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("byte_serialize_stack - new ByteSerializerStack", |b| {
         b.iter(|| {
-            black_box({
-                let _: ([u8; 128], usize) = to_bytes_stack(&inp).unwrap();
-            })
+            black_box::<([u8; 128], usize)>(to_bytes_stack(&inp).unwrap());
         })
     });
 }
@@ -34,10 +30,8 @@ fn reset_byte_serialize_heap(c: &mut Criterion) {
     let ser = &mut ByteSerializerHeap::default();
     c.bench_function("byte_serialize_heap - reset ByteSerializerHeap", |b| {
         b.iter(|| {
-            black_box({
-                ser.clear();
-                let _ = inp.byte_serialize_heap(ser).unwrap();
-            })
+            ser.clear();
+            black_box(inp.byte_serialize_heap(ser)).unwrap();
         })
     });
 }
@@ -46,9 +40,7 @@ fn new_byte_serialize_heap(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("byte_serialize_heap - new ByteSerializerHeap", |b| {
         b.iter(|| {
-            black_box({
-                let _: Bytes = to_bytes_heap(&inp).unwrap();
-            })
+            black_box::<Bytes>(to_bytes_heap(&inp).unwrap());
         })
     });
 }
@@ -59,11 +51,9 @@ fn reset_from_bytes(c: &mut Criterion) {
     let des = &mut ByteDeserializerSlice::new(ser.as_slice());
     c.bench_function("from_bytes - reset ByteDeserializerSlice", |b| {
         b.iter(|| {
-            black_box({
-                des.reset();
-                let _ = <Numbers as ByteDeserializeSlice<Numbers>>::byte_deserialize(des).unwrap();
-                // let _ = from_bytes::<Numbers>(&ser.as_slice());
-            })
+            des.reset();
+            black_box(<Numbers as ByteDeserializeSlice<Numbers>>::byte_deserialize(des).unwrap());
+            // let _ = from_bytes::<Numbers>(ser.as_slice());
         })
     });
 }
@@ -73,9 +63,7 @@ fn new_from_bytes(c: &mut Criterion) {
     let ser: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
     c.bench_function("from_bytes - new ByteDeserializerSlice", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = from_slice(&ser.as_slice()).unwrap();
-            })
+            black_box::<Numbers>(from_slice(ser.as_slice()).unwrap());
         })
     });
 }
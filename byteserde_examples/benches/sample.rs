@@ -46,16 +46,8 @@ impl Default for Floats {
 }
 
 #[rustfmt::skip]
-#[derive(Debug, PartialEq, ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, Serialize, Deserialize, Default)]
 pub struct Numbers {
     pub type_header: Integers,
     pub type_footer: Floats,
 }
-impl Default for Numbers {
-    fn default() -> Self {
-        Self {
-            type_header: Integers::default(),
-            type_footer: Floats::default(),
-        }
-    }
-}
@@ -6,9 +6,7 @@ fn to_vec(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("bincode::serialize", |b| {
         b.iter(|| {
-            black_box({
-                let _ = bincode::serialize(&inp).unwrap();
-            })
+            black_box(bincode::serialize(&inp).unwrap());
         })
     });
 }
@@ -18,9 +16,7 @@ fn from_vec(c: &mut Criterion) {
     let bincode = bincode::serialize(&inp).unwrap();
     c.bench_function("bincode::deserialize", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = bincode::deserialize(bincode.as_slice()).unwrap();
-            })
+            black_box::<Numbers>(bincode::deserialize(bincode.as_slice()).unwrap());
         })
     });
 }
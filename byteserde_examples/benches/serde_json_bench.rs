@@ -7,9 +7,7 @@ fn to_string(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("serde_json::to_string", |b| {
         b.iter(|| {
-            black_box({
-                let _ = serde_json::to_string(&inp).unwrap();
-            })
+            black_box(serde_json::to_string(&inp).unwrap());
         })
     });
 }
@@ -19,9 +17,7 @@ fn from_string(c: &mut Criterion) {
     let json = serde_json::to_string(&inp).unwrap();
     c.bench_function("serde_json::from_str", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = serde_json::from_str(&json).unwrap();
-            })
+            black_box::<Numbers>(serde_json::from_str(&json).unwrap());
         })
     });
 }
@@ -7,9 +7,7 @@ fn to_vec(c: &mut Criterion) {
     let inp = Numbers::default();
     c.bench_function("rmp_serde::to_vec", |b| {
         b.iter(|| {
-            black_box({
-                let _ = rmp_serde::to_vec(&inp).unwrap();
-            })
+            black_box(rmp_serde::to_vec(&inp).unwrap());
         })
     });
 }
@@ -19,9 +17,7 @@ fn from_vec(c: &mut Criterion) {
     let rmp = rmp_serde::to_vec(&inp).unwrap();
     c.bench_function("rmp_serde::from_read", |b| {
         b.iter(|| {
-            black_box({
-                let _: Numbers = rmp_serde::from_read(rmp.as_slice()).unwrap();
-            })
+            black_box::<Numbers>(rmp_serde::from_read(rmp.as_slice()).unwrap());
         })
     });
 }
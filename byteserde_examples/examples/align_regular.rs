@@ -0,0 +1,139 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use byteserde_types::prelude::*;
+use log::info;
+use unittest::setup;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Default)]
+#[byteserde(endian = "le")]
+struct Aligned {
+    msg_type: u8,
+    #[byteserde(align = 4)] // 3 bytes of padding inserted before
+    seq: u32,
+    flag: u8,
+    #[byteserde(align = 8)] // 7 bytes of padding inserted before
+    price: i64,
+}
+
+#[test]
+fn test_align() {
+    align()
+}
+fn align() {
+    setup::log::configure();
+    let inp = Aligned {
+        msg_type: 1,
+        seq: 2,
+        flag: 3,
+        price: 4,
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(
+        ser_stack.as_slice(),
+        &[1, 0, 0, 0, /* seq */ 2, 0, 0, 0, /* flag */ 3, 0, 0, 0, 0, 0, 0, 0, /* price */ 4, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Aligned = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+
+    // size & len
+    assert_eq!(Aligned::BYTE_SIZE, 24);
+    assert_eq!(inp.byte_len(), 24);
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(endian = "be", pad_to = 16, fill = 0x20)] // always 16 bytes, padded with ascii spaces
+struct Block {
+    id: u16,
+    #[byteserde(align = 4)]
+    qty: u32,
+    text: [u8; 4],
+}
+
+#[test]
+fn test_pad_to() {
+    pad_to()
+}
+fn pad_to() {
+    setup::log::configure();
+    let inp = Block {
+        id: 1,
+        qty: 2,
+        text: *b"abcd",
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), b"\x00\x01  \x00\x00\x00\x02abcd    ");
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize, padding is skipped
+    let out: Block = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Block = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    // size & len
+    assert_eq!(Block::BYTE_SIZE, 16);
+    assert_eq!(inp.byte_len(), 16);
+
+    // padding is relative to the start of the struct, hence is not affected by preceding bytes
+    let mut ser_stack = ByteSerializerStack::<128>::default();
+    ser_stack.serialize_bytes_slice(&[0xFF]).unwrap();
+    inp.byte_serialize_stack(&mut ser_stack).unwrap();
+    assert_eq!(&ser_stack.as_slice()[1..], b"\x00\x01  \x00\x00\x00\x02abcd    ");
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(pad_to = 8)]
+struct Small(
+    #[byteserde(deplete(4))]
+    StringAscii,
+);
+
+#[test]
+fn test_pad_to_overflow() {
+    pad_to_overflow()
+}
+fn pad_to_overflow() {
+    setup::log::configure();
+    let inp = Small(b"too long to fit".as_slice().into());
+    let err = to_serializer_heap(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Small #[byteserde(pad_to = 8)] serialized length 15 exceeds 8 bytes");
+
+    let err = from_slice::<Small>(&[b'a'; 4]).unwrap_err();
+    info!("err: {err}");
+
+    let out: Small = from_slice(&[b'a', b'b', b'c', b'd', 0, 0, 0, 0]).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Small(b"abcd".as_slice().into()));
+}
+
+fn main() {
+    align();
+    pad_to();
+    pad_to_overflow();
+}
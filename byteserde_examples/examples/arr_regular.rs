@@ -3,6 +3,7 @@ use std::mem::size_of;
 
 use byteserde::prelude::*;
 use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use byteserde_types::prelude::*;
 use log::info;
 use unittest::setup;
 
@@ -213,6 +214,124 @@ fn other_size_len() {
     assert_ne!(sz_of, sz_of_aligned);
     assert_eq!(ln_of, 7);
     assert_eq!(sz_of_aligned, 8);
+
+    // CAP is verified at compile time, ex: ByteSerializerStack<6> would fail to build
+    assert_eq!(ArrOther::BYTE_SIZE, sz_of);
+    let ser_stack: ByteSerializerStack<7> = to_serializer_stack_checked(&ArrOther::default()).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.len(), ArrOther::BYTE_SIZE);
+}
+
+/// manual implementation which only defines `byte_size()`, hence `BYTE_SIZE` is not available at compile time
+struct Legacy;
+impl ByteSerializedSizeOf for Legacy {
+    fn byte_size() -> usize {
+        2
+    }
+}
+#[derive(ByteSerializedSizeOf)]
+#[allow(dead_code)] // only the size is of interest
+struct ArrLegacy {
+    field_arr_legacy: [Legacy; 2],
+    field_u8: u8,
+}
+#[test]
+fn test_legacy_size() {
+    legacy_size()
+}
+
+fn legacy_size() {
+    setup::log::configure();
+    let sz_of = ArrLegacy::byte_size();
+    info!("sz_of: {sz_of}");
+    assert_eq!(sz_of, 5);
+}
+
+/// neither `Copy` nor `Default`
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+struct Label {
+    #[byteserde(replace( text.len() as u8 ))]
+    len: u8,
+    #[byteserde(deplete( len as usize ))]
+    text: StringAscii,
+}
+impl Label {
+    fn new(text: &[u8]) -> Self {
+        Self { len: text.len() as u8, text: text.into() }
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(endian = "be")]
+struct ArrAny {
+    field_arr_f32: [f32; 2],
+    field_arr_bool: [bool; 2],
+    field_arr_nested_u16: [[u16; 2]; 3],
+    field_arr_nested_i8: [[i8; 2]; 2],
+    field_arr_labels: [Label; 2],
+    field_arr_nested_labels: [[Label; 1]; 2],
+}
+
+#[test]
+fn test_any() {
+    any()
+}
+fn any() {
+    setup::log::configure();
+    let inp = ArrAny {
+        field_arr_f32: [1.5, -2.0],
+        field_arr_bool: [true, false],
+        field_arr_nested_u16: [[1, 2], [3, 4], [5, 6]],
+        field_arr_nested_i8: [[-1, 1], [-2, 2]],
+        field_arr_labels: [Label::new(b"ab"), Label::new(b"c")],
+        field_arr_nested_labels: [[Label::new(b"d")], [Label::new(b"")]],
+    };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // nested numerics respect struct level endian
+    assert_eq!(&ser_stack.as_slice()[8..10], &[0x01, 0x00]); // bools
+    assert_eq!(&ser_stack.as_slice()[10..14], &[0x00, 0x01, 0x00, 0x02]);
+    assert_eq!(&ser_stack.as_slice()[22..26], &[0xFF, 0x01, 0xFE, 0x02]);
+
+    let out: ArrAny = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(inp, out);
+    let out: ArrAny = from_serializer_heap(&ser_heap).unwrap();
+    assert_eq!(inp, out);
+
+    // 8 f32 + 2 bool + 12 u16 + 4 i8 + 3 + 2 labels + 2 + 1 labels
+    assert_eq!(inp.byte_len(), 8 + 2 + 12 + 4 + 3 + 2 + 2 + 1);
+    assert_eq!(inp.byte_len(), ser_stack.len());
+
+    // invalid bool is reported and nothing is assumed about the remaining elements
+    let mut bytes = ser_stack.as_slice().to_vec();
+    bytes[9] = 2;
+    let err = from_slice::<ArrAny>(&bytes).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq)]
+struct ArrNestedSize([[u16; 4]; 8], [[[u8; 2]; 3]; 2], [[Other; 2]; 2]);
+
+#[test]
+fn test_nested_size_len() {
+    nested_size_len()
+}
+fn nested_size_len() {
+    setup::log::configure();
+    let inp = ArrNestedSize([[1; 4]; 8], [[[2; 2]; 3]; 2], [[Other(3), Other(4)], [Other(5), Other(6)]]);
+    assert_eq!(ArrNestedSize::BYTE_SIZE, 2 * 4 * 8 + 2 * 3 * 2 + 2 * 2);
+    assert_eq!(inp.byte_len(), ArrNestedSize::BYTE_SIZE);
+    let ser: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    assert_eq!(ser.len(), ArrNestedSize::BYTE_SIZE);
+    let out: ArrNestedSize = from_serializer_stack(&ser).unwrap();
+    assert_eq!(inp, out);
 }
 
 fn main() {
@@ -222,4 +341,7 @@ fn main() {
     numerics_size_len();
     other();
     other_size_len();
+    legacy_size();
+    any();
+    nested_size_len();
 }
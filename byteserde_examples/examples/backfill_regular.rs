@@ -0,0 +1,119 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use byteserde_types::prelude::*;
+use log::info;
+use unittest::setup;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Message {
+    msg_type: u8,
+    #[byteserde(backfill_len(of = payload))] // length of payload is written after payload is serialized
+    payload_len: u16,
+    #[byteserde(deplete(payload_len as usize))]
+    payload: StringAscii,
+    trailer: u32,
+}
+impl Default for Message {
+    fn default() -> Self {
+        Message {
+            msg_type: 1,
+            payload_len: 0, // ignored during serialization
+            payload: b"hello backfill".as_slice().into(),
+            trailer: 0xDEAD_BEEF,
+        }
+    }
+}
+
+#[test]
+fn test_backfill_len() {
+    backfill_len()
+}
+fn backfill_len() {
+    setup::log::configure();
+    let inp_msg = Message::default();
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_msg).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice()[1..=2], 14_u16.to_be_bytes());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_msg).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_msg: Message = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_msg: {inp_msg:?}");
+    info!("out_msg: {out_msg:?}");
+    assert_eq!(out_msg, Message { payload_len: 14, ..inp_msg });
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Packet(
+    #[byteserde(backfill_len(of = 2), endian = "le")]
+    u16,
+    u8,
+    #[byteserde(deplete(_0 as usize))]
+    StringAscii,
+);
+
+#[test]
+fn test_backfill_len_tuple() {
+    backfill_len_tuple()
+}
+fn backfill_len_tuple() {
+    setup::log::configure();
+    let inp_pkt = Packet(0, 7, b"abc".as_slice().into());
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_pkt).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[3, 0, 7, b'a', b'b', b'c']);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_pkt).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_pkt: Packet = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_pkt: {inp_pkt:?}");
+    info!("out_pkt: {out_pkt:?}");
+    assert_eq!(out_pkt, Packet(3, inp_pkt.1, inp_pkt.2.clone()));
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, Debug)]
+struct Overflow {
+    #[byteserde(backfill_len(of = data))]
+    data_len: u8,
+    data: Vec<u8>,
+}
+
+#[test]
+fn test_backfill_len_overflow() {
+    backfill_len_overflow()
+}
+fn backfill_len_overflow() {
+    setup::log::configure();
+    let inp = Overflow { data_len: 0, data: vec![0; 256] };
+
+    let err = to_serializer_stack::<512, _>(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Overflow.data_len #[byteserde(backfill_len(of = data ))] length 256 does not fit into u8");
+
+    let err = to_serializer_heap(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Overflow.data_len #[byteserde(backfill_len(of = data ))] length 256 does not fit into u8");
+}
+
+fn main() {
+    backfill_len();
+    backfill_len_tuple();
+    backfill_len_overflow();
+}
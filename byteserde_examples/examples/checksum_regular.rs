@@ -0,0 +1,149 @@
+mod unittest;
+use byteserde::checksum::{crc16_ccitt, crc32, fletcher16, mod256};
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use byteserde_types::prelude::*;
+use log::info;
+use unittest::setup;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(endian = "be")]
+struct Frame {
+    msg_type: u8,
+    seq: u32,
+    #[byteserde(backfill_len(of = payload))]
+    payload_len: u16,
+    #[byteserde(deplete(payload_len as usize))]
+    payload: StringAscii,
+    #[byteserde(checksum = "crc16_ccitt", over = "payload..self")] // covers payload only
+    payload_crc: u16,
+    #[byteserde(checksum = "crc32", over = "start..self")] // covers all members above including payload_crc
+    crc: u32,
+}
+impl Default for Frame {
+    fn default() -> Self {
+        Frame {
+            msg_type: 1,
+            seq: 2,
+            payload_len: 0, // ignored during serialization
+            payload: b"hello checksum".as_slice().into(),
+            payload_crc: 0, // ignored during serialization
+            crc: 0,         // ignored during serialization
+        }
+    }
+}
+
+#[test]
+fn test_checksum() {
+    checksum()
+}
+fn checksum() {
+    setup::log::configure();
+    let inp_frm = Frame::default();
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_frm).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let bytes = ser_stack.as_slice();
+    let (body, crc) = bytes.split_at(bytes.len() - 4);
+    assert_eq!(crc, crc32(body).to_be_bytes());
+    let (payload, payload_crc) = body[7..].split_at(body.len() - 7 - 2);
+    assert_eq!(payload, b"hello checksum");
+    assert_eq!(payload_crc, crc16_ccitt(payload).to_be_bytes());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_frm).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_frm: Frame = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_frm: {inp_frm:?}");
+    info!("out_frm: {out_frm:?}");
+    assert_eq!(
+        out_frm,
+        Frame {
+            payload_len: 14,
+            payload_crc: crc16_ccitt(b"hello checksum"),
+            crc: crc32(body),
+            ..inp_frm
+        }
+    );
+}
+
+#[test]
+fn test_checksum_mismatch() {
+    checksum_mismatch()
+}
+fn checksum_mismatch() {
+    setup::log::configure();
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&Frame::default()).unwrap();
+
+    // corrupt the seq, only crc covers it
+    let mut bytes = ser_stack.as_slice().to_vec();
+    bytes[1] ^= 0xFF;
+    let err = from_slice::<Frame>(&bytes).unwrap_err();
+    info!("err: {err}");
+    assert!(matches!(err.kind(), ErrorKind::ChecksumMismatch { algorithm: "crc32", .. }));
+
+    // corrupt the payload, payload_crc is verified first
+    let mut bytes = ser_stack.as_slice().to_vec();
+    bytes[7] ^= 0xFF;
+    let err = from_slice::<Frame>(&bytes).unwrap_err();
+    info!("err: {err}");
+    match err.kind() {
+        ErrorKind::ChecksumMismatch { algorithm, expected, actual } => {
+            assert_eq!(algorithm, "crc16_ccitt");
+            assert_eq!(expected, crc16_ccitt(b"hello checksum") as u64);
+            assert_eq!(actual, crc16_ccitt(&bytes[7..21]) as u64);
+        }
+        _ => panic!("expected ChecksumMismatch, got: {err}"),
+    }
+    assert!(err.message.starts_with("Frame.payload_crc #[byteserde(checksum = \"crc16_ccitt\")] mismatch"));
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Trailers(
+    u8,
+    #[byteserde(deplete(3))]
+    StringAscii,
+    #[byteserde(checksum = "mod256", over = "1..self")]
+    u8,
+    #[byteserde(checksum = "fletcher16", endian = "le")]
+    u16,
+);
+
+#[test]
+fn test_checksum_tuple() {
+    checksum_tuple()
+}
+fn checksum_tuple() {
+    setup::log::configure();
+    let inp = Trailers(9, b"abc".as_slice().into(), 0, 0);
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let sum = mod256(b"abc");
+    let mut expected = vec![9, b'a', b'b', b'c', sum];
+    expected.extend_from_slice(&fletcher16(&expected).to_le_bytes());
+    assert_eq!(ser_stack.as_slice(), expected);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Trailers = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Trailers(9, inp.1.clone(), sum, fletcher16(&expected[..5])));
+}
+
+fn main() {
+    checksum();
+    checksum_mismatch();
+    checksum_tuple();
+}
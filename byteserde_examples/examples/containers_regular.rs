@@ -0,0 +1,125 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Inner {
+    id: u16,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Fixed {
+    pair: (u16, u32),
+    #[byteserde(endian = "le")] // applies to every member of the tuple
+    le_pair: (u16, [u8; 2]),
+    boxed: Box<Inner>,
+    pairs: [(u8, i16); 2],
+}
+
+#[test]
+fn test_fixed() {
+    fixed()
+}
+fn fixed() {
+    setup::log::configure();
+    let inp = Fixed {
+        pair: (1, 2),
+        le_pair: (3, [4, 5]),
+        boxed: Box::new(Inner { id: 6 }),
+        pairs: [(7, -1), (8, 9)],
+    };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x02, // pair
+        0x03, 0x00, 0x04, 0x05,             // le_pair
+        0x00, 0x06,                         // boxed
+        0x07, 0xFF, 0xFF, 0x08, 0x00, 0x09, // pairs
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: Fixed = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Fixed = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    assert_eq!(Fixed::BYTE_SIZE, 18);
+    assert_eq!(inp.byte_len(), 18);
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Variable {
+    tags_count: u8,
+    #[byteserde(count(tags_count))]
+    tags: Vec<[u8; 4]>,
+    #[byteserde(count(2))]
+    points: Vec<(u16, u16)>,
+    #[byteserde(deplete(2))] // number of bytes of the boxed Vec<u8>
+    #[allow(clippy::box_collection)]
+    head: Box<Vec<u8>>,
+    #[byteserde(presence = "flag")]
+    #[byteserde(deplete(2))] // number of elements of the Vec<u16> when present
+    qtys: Option<Vec<u16>>,
+    tail: Vec<[u16; 3]>, // all remaining bytes
+}
+
+#[test]
+fn test_variable() {
+    variable()
+}
+fn variable() {
+    setup::log::configure();
+    let inp = Variable {
+        tags_count: 2,
+        tags: vec![*b"ABCD", *b"EFGH"],
+        points: vec![(1, 2), (3, 4)],
+        head: Box::new(b"hi".to_vec()),
+        qtys: Some(vec![7, 8]),
+        tail: vec![[9, 10, 11]],
+    };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x02, b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', // tags
+        0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04,       // points
+        b'h', b'i',                                           // head
+        0x01, 0x00, 0x07, 0x00, 0x08,                         // qtys
+        0x00, 0x09, 0x00, 0x0A, 0x00, 0x0B,                   // tail
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: Variable = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Variable = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+    assert_eq!(inp.byte_len(), 30);
+
+    // absent Option<Vec<T>> & empty trailing Vec<[T; N]>
+    let inp = Variable { qtys: None, tail: vec![], ..inp };
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_heap.len(), 20);
+    assert_eq!(inp.byte_len(), 20);
+    let out: Variable = from_serializer_heap(&ser_heap).unwrap();
+    assert_eq!(out, inp);
+}
+
+fn main() {
+    fixed();
+    variable();
+}
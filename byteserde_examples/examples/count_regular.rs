@@ -0,0 +1,117 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use log::info;
+use unittest::setup;
+
+/// variable length element, `len` is followed by `len` bytes of text
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+struct Item {
+    #[byteserde(backfill_len(of = text))]
+    len: u8,
+    #[byteserde(count(len))]
+    text: Vec<u8>,
+}
+impl From<&[u8]> for Item {
+    fn from(text: &[u8]) -> Self {
+        Item { len: text.len() as u8, text: text.to_vec() }
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Batch {
+    qty_count: u8,
+    #[byteserde(count(qty_count))] // number of elements
+    qtys: Vec<u16>,
+    #[byteserde(backfill_len(of = items))]
+    items_len: u16,
+    #[byteserde(byte_len(items_len))] // number of bytes occupied by all elements
+    items: Vec<Item>,
+    trailer: u8,
+}
+impl Default for Batch {
+    fn default() -> Self {
+        Batch {
+            qty_count: 2,
+            qtys: vec![10, 20],
+            items_len: 8,
+            items: vec![b"ab".as_slice().into(), b"cde".as_slice().into(), b"".as_slice().into()],
+            trailer: 0xEE,
+        }
+    }
+}
+
+#[test]
+fn test_count() {
+    count()
+}
+fn count() {
+    setup::log::configure();
+    let inp_bat = Batch::default();
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_bat).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(
+        ser_stack.as_slice(),
+        &[2, /* qtys */ 0, 10, 0, 20, /* items_len */ 0, 8, /* items */ 2, b'a', b'b', 3, b'c', b'd', b'e', 0, /* trailer */ 0xEE]
+    );
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_bat).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_bat: Batch = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_bat: {inp_bat:?}");
+    info!("out_bat: {out_bat:?}");
+    assert_eq!(out_bat, inp_bat);
+    let out_bat: Batch = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out_bat, inp_bat);
+}
+
+#[test]
+fn test_byte_len_errors() {
+    byte_len_errors()
+}
+fn byte_len_errors() {
+    setup::log::configure();
+
+    // byte_len budget is larger then available bytes
+    let err = from_slice::<Batch>(&[0, 0, 9, 1, b'a']).unwrap_err();
+    info!("err: {err}");
+
+    // last element does not fit into the byte_len budget
+    let err = from_slice::<Batch>(&[0, 0, 3, 1, b'a', 5, b'b', 0xEE]).unwrap_err();
+    info!("err: {err}");
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Pairs(u8, #[byteserde(byte_len(_0 as usize * 2))] Vec<u16>, u8);
+
+#[test]
+fn test_byte_len_tuple() {
+    byte_len_tuple()
+}
+fn byte_len_tuple() {
+    setup::log::configure();
+    let inp = Pairs(2, vec![1, 2], 3);
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.len(), 6);
+
+    let out: Pairs = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+}
+
+fn main() {
+    count();
+    byte_len_errors();
+    byte_len_tuple();
+}
@@ -0,0 +1,147 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use log::info;
+use unittest::setup;
+
+/// options negotiated at session logon and not present in the byte stream
+#[derive(Debug)]
+struct Session {
+    extended: bool,
+    text_len: usize,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be", ctx = Session)]
+struct Body {
+    qty: u16,
+    #[byteserde(count(ctx.text_len))] // context is available as `ctx` in attribute expressions
+    text: Vec<u8>,
+    #[byteserde(when = ctx.extended)]
+    price: Option<u32>,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be", ctx = Session)]
+struct Message {
+    seq: u32,
+    body: Body, // context is passed through to nested structs
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be", ctx = Session)]
+struct Batch {
+    bodies: [Body; 2], // including elements of containers
+    #[byteserde(deplete(2 + ctx.text_len))] // and depleted members
+    last: Body,
+}
+
+#[test]
+fn test_ctx() {
+    ctx()
+}
+fn ctx() {
+    setup::log::configure();
+    let session = Session { extended: true, text_len: 3 };
+    let inp = Message {
+        seq: 1,
+        body: Body {
+            qty: 2,
+            text: b"abc".to_vec(),
+            price: Some(3),
+        },
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack_with(&inp, &session).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 0, 0, 1, /* qty */ 0, 2, /* text */ b'a', b'b', b'c', /* price */ 0, 0, 0, 3]);
+    assert_eq!(inp.byte_len(), ser_stack.len());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap_with(&inp, &session).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Message = from_slice_with(ser_stack.as_slice(), &session).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Message = from_bytes_with(ser_heap.as_slice().to_vec().into(), &session).unwrap();
+    assert_eq!(out, inp);
+
+    // same bytes read with a different session
+    let session = Session { extended: false, text_len: 2 };
+    let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+    let out: Message = des.deserialize_with(&session).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(
+        out,
+        Message {
+            seq: 1,
+            body: Body {
+                qty: 2,
+                text: b"ab".to_vec(),
+                price: None
+            }
+        }
+    );
+    assert_eq!(des.remaining(), 5);
+}
+
+#[test]
+fn test_ctx_nested() {
+    ctx_nested()
+}
+fn ctx_nested() {
+    setup::log::configure();
+    let session = Session { extended: false, text_len: 2 };
+    let body = |qty: u16, text: &[u8]| Body { qty, text: text.to_vec(), price: None };
+    let inp = Batch {
+        bodies: [body(1, b"ab"), body(2, b"cd")],
+        last: body(3, b"ef"),
+    };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack_with(&inp, &session).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 1, b'a', b'b', 0, 2, b'c', b'd', /* last */ 0, 3, b'e', b'f']);
+    assert_eq!(inp.byte_len(), ser_stack.len());
+    let ser_heap: ByteSerializerHeap = to_serializer_heap_with(&inp, &session).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: Batch = from_slice_with(ser_stack.as_slice(), &session).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Batch = from_bytes_with(ser_heap.as_slice().to_vec().into(), &session).unwrap();
+    assert_eq!(out, inp);
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+#[byteserde(ctx = u8)]
+struct Masked(#[byteserde(replace(*ctx))] u8, u8);
+
+#[test]
+fn test_ctx_tuple() {
+    ctx_tuple()
+}
+fn ctx_tuple() {
+    setup::log::configure();
+    let mut ser_stack = ByteSerializerStack::<128>::default();
+    ser_stack.serialize_with(&Masked(0, 1), &0xFF).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0xFF, 1]);
+
+    let out: Masked = from_slice_with(ser_stack.as_slice(), &0).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Masked(0xFF, 1));
+}
+
+fn main() {
+    ctx();
+    ctx_nested();
+    ctx_tuple();
+}
@@ -0,0 +1,173 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "runtime")]
+struct Point {
+    x: u16,
+    y: i32,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "runtime")] // byte order is set on serializer & deserializer
+struct Image {
+    #[byteserde(backfill_len(of = points))]
+    points_len: u16,
+    #[byteserde(byte_len(points_len))]
+    points: Vec<Point>,
+    dims: [u16; 2],
+    #[byteserde(endian = "be")] // member level endian takes precedence
+    magic: u16,
+}
+
+/// byte order marker "II" little endian or "MM" big endian followed by the [Image]
+fn read(bytes: &[u8]) -> byteserde::error::Result<Image> {
+    let mut des = ByteDeserializerSlice::new(bytes);
+    let endian = match des.deserialize_bytes_slice(2)? {
+        b"II" => Endian::Little,
+        b"MM" => Endian::Big,
+        other => {
+            return Err(SerDesError::with_kind(ErrorKind::UnexpectedValue, format!("unexpected byte order marker: {other:?}")))
+        }
+    };
+    des.set_endian(endian).deserialize()
+}
+
+#[test]
+fn test_endian() {
+    endian()
+}
+fn endian() {
+    setup::log::configure();
+    let inp = Image {
+        points_len: 6,
+        points: vec![Point { x: 1, y: -1 }],
+        dims: [2, 3],
+        magic: 0xCAFE,
+    };
+
+    for (marker, endian, expected) in [
+        (b"II", Endian::Little, vec![6, 0, /* x */ 1, 0, /* y */ 0xFF, 0xFF, 0xFF, 0xFF, /* dims */ 2, 0, 3, 0, /* magic */ 0xCA, 0xFE]),
+        (b"MM", Endian::Big, vec![0, 6, /* x */ 0, 1, /* y */ 0xFF, 0xFF, 0xFF, 0xFF, /* dims */ 0, 2, 0, 3, /* magic */ 0xCA, 0xFE]),
+    ] {
+        // stack
+        let mut ser_stack = ByteSerializerStack::<128>::default();
+        ser_stack.serialize_bytes_slice(marker).unwrap();
+        ser_stack.set_endian(endian).serialize(&inp).unwrap();
+        info!("endian: {endian:?}, ser_stack: {ser_stack:#x}");
+        assert_eq!(&ser_stack.as_slice()[2..], expected);
+
+        // heap
+        let mut ser_heap = ByteSerializerHeap::default();
+        ser_heap.serialize_bytes_slice(marker).unwrap();
+        ser_heap.set_endian(endian).serialize(&inp).unwrap();
+        assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+        // deserialize
+        let out = read(ser_stack.as_slice()).unwrap();
+        info!("out: {out:?}");
+        assert_eq!(out, inp);
+        let mut des = ByteDeserializerBytes::new(ser_heap.as_slice()[2..].to_vec().into());
+        let out: Image = des.set_endian(endian).deserialize().unwrap();
+        assert_eq!(out, inp);
+    }
+
+    // size & len do not depend on endian
+    assert_eq!(Point::BYTE_SIZE, 6);
+    assert_eq!(inp.byte_len(), 14);
+
+    let err = read(b"XX").unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Mixed(#[byteserde(endian = "runtime")] u16, #[byteserde(endian = "le")] u16);
+
+#[test]
+fn test_endian_tuple() {
+    endian_tuple()
+}
+fn endian_tuple() {
+    setup::log::configure();
+    let mut ser_stack = ByteSerializerStack::<128>::default();
+    ser_stack.set_endian(Endian::Big).serialize(&Mixed(1, 2)).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 1, 2, 0]);
+
+    let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+    let out: Mixed = des.set_endian(Endian::Big).deserialize().unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Mixed(1, 2));
+}
+
+/// generic container knows nothing about endianess, it is carried by `T`
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+struct Tagged<T: ByteSerializeStack + ByteSerializeHeap + ByteDeserializeSlice<T> + ByteDeserializeBytes<T> + ByteSerializedLenOf> {
+    tag: u8,
+    value: T,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+struct Wrapped {
+    be: Tagged<Be<u32>>,
+    le: Tagged<Le<u32>>,
+    arr: [Be<u16>; 2],
+    #[byteserde(count(2))]
+    vec: Vec<Le<u16>>,
+    #[byteserde(presence = "flag")]
+    opt: Option<Be<u16>>,
+}
+
+#[test]
+fn test_endian_wrappers() {
+    endian_wrappers()
+}
+fn endian_wrappers() {
+    setup::log::configure();
+    let inp = Wrapped {
+        be: Tagged { tag: 1, value: Be(2) },
+        le: Tagged { tag: 3, value: 4.into() },
+        arr: [Be(5), Be(6)],
+        vec: vec![Le(7), Le(8)],
+        opt: Some(Be(9)),
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(
+        ser_stack.as_slice(),
+        &[1, 0, 0, 0, 2, /* le */ 3, 4, 0, 0, 0, /* arr */ 0, 5, 0, 6, /* vec */ 7, 0, 8, 0, /* opt */ 1, 0, 9]
+    );
+    assert_eq!(inp.byte_len(), ser_stack.len());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Wrapped = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Wrapped = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    // wrappers deref to the numeric
+    assert_eq!(*out.be.value + 1, 3);
+    assert_eq!(out.le.value.into_inner(), 4);
+    assert_eq!(Be::<u16>::BYTE_SIZE, 2);
+}
+
+fn main() {
+    endian();
+    endian_tuple();
+    endian_wrappers();
+}
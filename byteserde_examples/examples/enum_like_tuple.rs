@@ -37,6 +37,7 @@ struct Variant2b {
 }
 
 #[rustfmt::skip]
+#[allow(dead_code)] // TODO used by V3 once struct like variants are supported
 #[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf,Debug, PartialEq)]
 struct Variant3 {
     #[byteserde(replace(Header(Variant3::tag())))]
@@ -44,6 +45,7 @@ struct Variant3 {
     data: u128,
 }
 #[rustfmt::skip]
+#[allow(dead_code)]
 impl Variant3 { fn tag() -> u16 { 3 } }
 
 #[rustfmt::skip]
@@ -83,8 +85,8 @@ fn enum_tuple_like() {
 
     for msg in &msg_inp {
         info!("ser: {:?}", msg);
-        let _ = msg.byte_serialize_stack(&mut ser_stck).unwrap();
-        let _ = msg.byte_serialize_heap(&mut ser_heap).unwrap();
+        msg.byte_serialize_stack(&mut ser_stck).unwrap();
+        msg.byte_serialize_heap(&mut ser_heap).unwrap();
     }
     info!("ser_stck: {:#x}", ser_stck);
     assert_eq!(ser_stck.as_slice(), ser_heap.as_slice());
@@ -0,0 +1,118 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, Debug, PartialEq, Clone, Copy, Default)]
+struct Version(u8, u8);
+impl Version {
+    const CURRENT: Version = Version(1, 2);
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, Debug, PartialEq, Default)]
+#[byteserde(endian = "be")]
+struct Header {
+    #[byteserde(expect( *b"BSDE" ))] // byte array magic
+    magic: [u8; 4],
+    #[byteserde(expect( 0xCAFE ))] // numeric
+    marker: u16,
+    #[byteserde(expect( Version::CURRENT ))] // nested struct
+    version: Version,
+    seq: u32,
+}
+
+#[test]
+fn test_expect() {
+    expect()
+}
+fn expect() {
+    setup::log::configure();
+    let inp_hdr = Header { seq: 10, ..Default::default() };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_hdr).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[b'B', b'S', b'D', b'E', 0xCA, 0xFE, 1, 2, 0, 0, 0, 10]);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_hdr).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_hdr: Header = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_hdr: {inp_hdr:?}");
+    info!("out_hdr: {out_hdr:?}");
+    assert_eq!(
+        out_hdr,
+        Header {
+            magic: *b"BSDE",
+            marker: 0xCAFE,
+            version: Version::CURRENT,
+            ..inp_hdr
+        }
+    );
+}
+
+#[test]
+fn test_expect_mismatch() {
+    expect_mismatch()
+}
+fn expect_mismatch() {
+    setup::log::configure();
+    let bytes = to_bytes_heap(&Header::default()).unwrap().to_vec();
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    let err = from_slice::<Header>(&wrong_magic).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+    assert_eq!(err.message, "Header.magic #[byteserde(expect( .. ))] expected: [66, 83, 68, 69] actual: [88, 83, 68, 69]");
+
+    let mut wrong_marker = bytes.clone();
+    wrong_marker[5] = 0xFF;
+    let err = from_slice::<Header>(&wrong_marker).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Header.marker #[byteserde(expect( .. ))] expected: 51966 actual: 51967");
+
+    let mut wrong_version = bytes;
+    wrong_version[7] = 3;
+    let err = from_slice::<Header>(&wrong_version).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Header.version #[byteserde(expect( .. ))] expected: Version(1, 2) actual: Version(1, 3)");
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Tagged(
+    #[byteserde(expect( b'T' ))]
+    u8,
+    i32,
+);
+
+#[test]
+fn test_expect_tuple() {
+    expect_tuple()
+}
+fn expect_tuple() {
+    setup::log::configure();
+    let inp = Tagged(0, -1);
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let out: Tagged = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Tagged(b'T', -1));
+
+    let err = from_slice::<Tagged>(&[b'X', 0, 0, 0, 0]).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Tagged.0 #[byteserde(expect( .. ))] expected: 84 actual: 88");
+}
+
+fn main() {
+    expect();
+    expect_mismatch();
+    expect_tuple();
+}
@@ -117,7 +117,7 @@ fn strings_len() {
     let ln_of = inp_str.byte_len();
     info!("inp_str: {inp_str:?}");
     info!("ln_of: {ln_of}");
-    assert_eq!(ln_of, 6);
+    assert_eq!(ln_of, 4 + 6); // u32 len prefix + 12345 + a
 
     let inp_str = StringsStructRegular::<String, char> {
         field_string: "1234567890".to_string(),
@@ -126,7 +126,7 @@ fn strings_len() {
     let ln_of = inp_str.byte_len();
     info!("inp_str: {inp_str:?}");
     info!("ln_of: {ln_of}");
-    assert_eq!(ln_of, 11);
+    assert_eq!(ln_of, 4 + 11);
 }
 
 #[rustfmt::skip]
@@ -176,12 +176,81 @@ fn nested_len() {
     setup::log::configure();
     let inp_nes = NestedStructRegular::<1, 2, String, char> {
         field_numbers: Default::default(), // len => 1 * 2(u16) + 2 * 2(u16) = 6
-        field_strings: Default::default(), // len => hello + h = 6
+        field_strings: Default::default(), // len => u32 len prefix + hello + h = 10
     };
     let ln_of = inp_nes.byte_len();
     info!("inp_nes: {inp_nes:?}");
     info!("ln_of: {ln_of}");
-    assert_eq!(ln_of, 12);
+    assert_eq!(ln_of, 16);
+}
+/// no bounds on `T`, each derive adds the bound it needs to its own `where` clause
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, 
+        ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(endian = "be")]
+pub struct TagValueElement<T> {
+    tag: u8,
+    value: T,
+    #[byteserde(skip)]
+    _received: Option<T>,
+}
+
+#[test]
+fn test_inferred_bounds() {
+    inferred_bounds()
+}
+fn inferred_bounds() {
+    setup::log::configure();
+    let inp = TagValueElement { tag: 1, value: NumbersStructRegular::<1, 1>::default(), _received: None };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: TagValueElement<NumbersStructRegular<1, 1>> = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    assert_eq!(TagValueElement::<NumbersStructRegular<1, 1>>::BYTE_SIZE, 5);
+    assert_eq!(inp.byte_len(), 5);
+}
+
+pub trait Protocol {
+    type Header;
+}
+#[derive(Debug, PartialEq)]
+pub struct Fix;
+impl Protocol for Fix {
+    type Header = NumbersStructRegular<1, 1>;
+}
+
+/// inferred bound `P: ..` is wrong since only `P::Header` is serialized, hence it is replaced with `#[byteserde(bound = "..")]`
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, 
+        ByteSerializedLenOf, Debug, PartialEq)]
+#[byteserde(bound = "P::Header: ByteSerializeStack + ByteSerializeHeap + ByteDeserializeSlice<P::Header> + ByteSerializedLenOf")]
+pub struct Frame<P: Protocol> {
+    seq: u32,
+    header: P::Header,
+}
+
+#[test]
+fn test_bound_override() {
+    bound_override()
+}
+fn bound_override() {
+    setup::log::configure();
+    let inp = Frame::<Fix> { seq: 1, header: NumbersStructRegular::default() };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: Frame<Fix> = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    assert_eq!(inp.byte_len(), 8);
 }
 fn main() {
     numeric();
@@ -190,4 +259,6 @@ fn main() {
     strings_len();
     nested();
     nested_len();
+    inferred_bounds();
+    bound_override();
 }
@@ -0,0 +1,133 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "le")]
+struct Entry {
+    price: u32,
+    qty: u16,
+}
+
+/// newer version of [Entry] with an extra member appended at the end
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "le")]
+struct EntryV2 {
+    price: u32,
+    qty: u16,
+    side: u8,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "le")]
+struct Book {
+    seq: u32,
+    #[byteserde(group(block_len = "u16", count = "u16"))] // header is followed by `count` entries of `block_len` bytes each
+    entries: Vec<Entry>,
+    #[byteserde(group(block_len = "u8", count = "u8"))]
+    ids: Vec<u16>,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "le")]
+struct BookV2 {
+    seq: u32,
+    #[byteserde(group(block_len = "u16", count = "u16"))]
+    entries: Vec<EntryV2>,
+    #[byteserde(group(block_len = "u8", count = "u8"))]
+    ids: Vec<u16>,
+}
+
+#[test]
+fn test_group() {
+    group()
+}
+fn group() {
+    setup::log::configure();
+    let inp = Book {
+        seq: 1,
+        entries: vec![Entry { price: 2, qty: 3 }, Entry { price: 4, qty: 5 }],
+        ids: vec![6],
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(
+        ser_stack.as_slice(),
+        &[1, 0, 0, 0, /* block_len */ 6, 0, /* count */ 2, 0, 2, 0, 0, 0, 3, 0, 4, 0, 0, 0, 5, 0, /* block_len */ 2, /* count */ 1, 6, 0]
+    );
+    assert_eq!(inp.byte_len(), ser_stack.len());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Book = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Book = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+}
+
+#[test]
+fn test_group_schema_evolution() {
+    group_schema_evolution()
+}
+fn group_schema_evolution() {
+    setup::log::configure();
+    let inp = BookV2 {
+        seq: 1,
+        entries: vec![EntryV2 { price: 2, qty: 3, side: b'B' }, EntryV2 { price: 4, qty: 5, side: b'S' }],
+        ids: vec![6],
+    };
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+
+    // older reader skips trailing `side` of each entry using block_len
+    let out: Book = from_slice(ser_heap.as_slice()).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(
+        out,
+        Book {
+            seq: 1,
+            entries: vec![Entry { price: 2, qty: 3 }, Entry { price: 4, qty: 5 }],
+            ids: vec![6],
+        }
+    );
+    let out: Book = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out.entries.len(), 2);
+
+    // newer reader fails when block_len is shorter then the entry
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&out).unwrap();
+    let err = from_slice::<BookV2>(ser_heap.as_slice()).unwrap_err();
+    info!("err: {err}");
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq)]
+struct Overflow(#[byteserde(group(block_len = "u8", count = "u8"))] Vec<u8>);
+
+#[test]
+fn test_group_overflow() {
+    group_overflow()
+}
+fn group_overflow() {
+    setup::log::configure();
+    let inp = Overflow(vec![0; 256]);
+    let err = to_serializer_heap(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Overflow.0 #[byteserde(group( .. ))] count 256 does not fit into u8");
+}
+
+fn main() {
+    group();
+    group_schema_evolution();
+    group_overflow();
+}
@@ -0,0 +1,89 @@
+mod unittest;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use indexmap::IndexMap;
+use log::info;
+use unittest::setup;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Default)]
+#[byteserde(endian = "be")]
+struct Snapshot {
+    #[byteserde(count_prefix = "u16")] // sorted by key
+    prices: BTreeMap<u16, u32>,
+    #[byteserde(count_prefix = "u8")] // sorted by key, hence K: Ord
+    params: HashMap<u8, (u16, [u8; 2])>,
+    flags_count: u8,
+    #[byteserde(count(flags_count))]
+    flags: BTreeSet<u16>,
+    #[byteserde(count_prefix = "u8")] // insertion order
+    #[byteserde(endian = "le")] // applies to the count prefix, keys & values
+    legs: IndexMap<u16, i16>,
+    tail: HashSet<u8>, // all remaining bytes
+}
+
+#[test]
+fn test_map() {
+    map()
+}
+fn map() {
+    setup::log::configure();
+    let inp = Snapshot {
+        prices: BTreeMap::from([(2, 200), (1, 100)]),
+        params: HashMap::from([(9, (1, *b"ab")), (3, (2, *b"cd"))]),
+        flags_count: 2,
+        flags: BTreeSet::from([7, 5]),
+        legs: IndexMap::from([(2, -1), (1, 1)]),
+        tail: HashSet::from([0xFF, 0x00]),
+    };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x64, 0x00, 0x02, 0x00, 0x00, 0x00, 0xC8, // prices
+        0x02, 0x03, 0x00, 0x02, b'c', b'd', 0x09, 0x00, 0x01, b'a', b'b',                   // params
+        0x02, 0x00, 0x05, 0x00, 0x07,                                                       // flags_count & flags
+        0x02, 0x02, 0x00, 0xFF, 0xFF, 0x01, 0x00, 0x01, 0x00,                               // legs
+        0x00, 0xFF,                                                                         // tail
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+    assert_eq!(inp.byte_len(), 41);
+
+    let out: Snapshot = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    assert_eq!(out.legs.keys().collect::<Vec<_>>(), vec![&2, &1]);
+    let out: Snapshot = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    // HashMap & HashSet serialization does not depend on their insertion order
+    let rev = Snapshot {
+        params: HashMap::from([(3, (2, *b"cd")), (9, (1, *b"ab"))]),
+        tail: HashSet::from([0x00, 0xFF]),
+        ..inp.clone()
+    };
+    assert_eq!(to_serializer_heap(&rev).unwrap().as_slice(), ser_heap.as_slice());
+
+    // duplicate keys are rejected
+    #[rustfmt::skip]
+    let err = from_slice::<Snapshot>(&[
+        0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x64, 0x00, 0x01, 0x00, 0x00, 0x00, 0xC8, // prices
+    ]).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+    assert_eq!(err.message, "Snapshot.prices duplicate key in entry 1");
+
+    // count which does not fit into the prefix
+    let inp = Snapshot { tail: (0..=255).collect(), legs: (0..256).map(|k| (k, 0)).collect(), ..Default::default() };
+    let err = to_serializer_heap(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Snapshot.legs #[byteserde(count_prefix = ..)] count 256 does not fit into u8");
+}
+
+fn main() {
+    map();
+}
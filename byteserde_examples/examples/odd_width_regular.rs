@@ -0,0 +1,70 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+/// ITCH like message with a `6` byte nanoseconds since midnight timestamp
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "be")]
+struct Trade {
+    stock_locate: u16,
+    timestamp: U48,
+    #[byteserde(endian = "le")]
+    price_delta: I24,
+    ids: [U40; 2],
+}
+
+#[test]
+fn test_odd_width() {
+    odd_width()
+}
+fn odd_width() {
+    setup::log::configure();
+    let inp = Trade {
+        stock_locate: 1,
+        timestamp: U48(0x0102_0304_0506),
+        price_delta: I24(-2),
+        ids: [U40(7), U40(U40::MAX)],
+    };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x00, 0x01,                         // stock_locate
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // timestamp
+        0xFE, 0xFF, 0xFF,                   // price_delta
+        0x00, 0x00, 0x00, 0x00, 0x07,       // ids[0]
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF,       // ids[1]
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // signed variants are sign extended
+    let out: Trade = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    assert_eq!(out.price_delta.0, -2);
+    let out: Trade = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    assert_eq!(Trade::BYTE_SIZE, 21);
+    assert_eq!(inp.byte_len(), 21);
+
+    // values which do not fit are rejected on serialize
+    let err = to_serializer_stack::<128, _>(&Trade { timestamp: U48(U48::MAX + 1), ..inp }).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::Other);
+    let err = to_serializer_heap(&Trade { price_delta: I24(I24::MIN - 1), ..inp }).unwrap_err();
+    info!("err: {err}");
+
+    // checked construction
+    assert!(U48::try_from(U48::MAX + 1).is_err());
+    assert_eq!(I24::try_from(-1).unwrap(), I24(-1));
+}
+
+fn main() {
+    odd_width();
+}
@@ -0,0 +1,154 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "be")]
+struct Party {
+    id: u16,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Default)]
+#[byteserde(endian = "be")]
+struct Flagged {
+    #[byteserde(presence = "flag")] // u8 flag followed by value when present
+    price: Option<u32>,
+    #[byteserde(presence = "flag")]
+    party: Option<Party>,
+    qty: u16,
+}
+
+#[test]
+fn test_presence_flag() {
+    presence_flag()
+}
+fn presence_flag() {
+    setup::log::configure();
+    let inp = Flagged {
+        price: Some(5),
+        party: None,
+        qty: 7,
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[1, 0, 0, 0, 5, /* party */ 0, /* qty */ 0, 7]);
+    assert_eq!(inp.byte_len(), 8);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Flagged = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Flagged = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    // flag other then 0 or 1
+    let err = from_slice::<Flagged>(&[2, 0, 0, 0, 5, 0, 0, 7]).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+    assert_eq!(err.message, "Flagged.price #[byteserde(presence = \"flag\")] expected: 0 or 1 actual: 2");
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Default)]
+#[byteserde(endian = "be", presence_map = "u16")] // bitmap is written ahead of all members
+struct Mapped {
+    seq: u32,
+    bid: Option<u32>,    // bit 0
+    ask: Option<u32>,    // bit 1
+    qty: u16,
+    party: Option<Party>, // bit 2
+}
+
+#[test]
+fn test_presence_map() {
+    presence_map()
+}
+fn presence_map() {
+    setup::log::configure();
+    let inp = Mapped {
+        seq: 1,
+        bid: None,
+        ask: Some(2),
+        qty: 3,
+        party: Some(Party { id: 4 }),
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 0b110, /* seq */ 0, 0, 0, 1, /* ask */ 0, 0, 0, 2, /* qty */ 0, 3, /* party */ 0, 4]);
+    assert_eq!(inp.byte_len(), 14);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Mapped = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp: {inp:?}");
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Mapped = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+
+    // nothing present
+    let inp = Mapped::default();
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 0, 0, 0, 0, 0, 0, 0]);
+    let out: Mapped = from_serializer_stack(&ser_stack).unwrap();
+    assert_eq!(out, inp);
+}
+
+/// `Option<u8>` encoded as a single byte where `0` is `None`
+mod zero_none {
+    use byteserde::prelude::*;
+
+    pub fn serialize<S: ByteSerializer>(v: &Option<u8>, ser: &mut S) -> byteserde::error::Result<()> {
+        ser.serialize_bytes_slice(&[v.unwrap_or(0)])?;
+        Ok(())
+    }
+    pub fn deserialize<D: ByteDeserializer>(des: &mut D) -> byteserde::error::Result<Option<u8>> {
+        let v: u8 = des.deserialize_be()?;
+        Ok(Some(v).filter(|v| *v != 0))
+    }
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+#[byteserde(presence_map = "u8")]
+struct Pair(Option<u8>, #[byteserde(with = zero_none)] Option<u8>, Option<u8>); // with member is not assigned a bit
+
+#[test]
+fn test_presence_map_tuple() {
+    presence_map_tuple()
+}
+fn presence_map_tuple() {
+    setup::log::configure();
+    let inp = Pair(None, None, Some(9));
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0b10, 0, 9]);
+
+    let out: Pair = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+}
+
+fn main() {
+    presence_flag();
+    presence_map();
+    presence_map_tuple();
+}
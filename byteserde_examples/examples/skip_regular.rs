@@ -0,0 +1,113 @@
+mod unittest;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, Clone)]
+#[byteserde(endian = "be")]
+struct Quote {
+    bid: u32,
+    #[byteserde(skip)] // filled with Default::default() during deserialization
+    cached_spread: u32,
+    ask: u32,
+    #[byteserde(skip, default = Some(Instant::now()))] // filled with expression during deserialization
+    received: Option<Instant>,
+}
+
+#[test]
+fn test_skip() {
+    skip()
+}
+fn skip() {
+    setup::log::configure();
+    let inp_qt = Quote {
+        bid: 1,
+        cached_spread: 1,
+        ask: 2,
+        received: None,
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_qt).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 0, 0, 1, 0, 0, 0, 2]);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_qt).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_qt: Quote = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_qt: {inp_qt:?}");
+    info!("out_qt: {out_qt:?}");
+    assert_eq!(out_qt.bid, inp_qt.bid);
+    assert_eq!(out_qt.ask, inp_qt.ask);
+    assert_eq!(out_qt.cached_spread, 0);
+    assert!(out_qt.received.is_some());
+}
+
+#[test]
+fn test_skip_size_len() {
+    skip_size_len()
+}
+fn skip_size_len() {
+    setup::log::configure();
+    let inp_qt = Quote {
+        bid: 1,
+        cached_spread: 1,
+        ask: 2,
+        received: Some(Instant::now()),
+    };
+    info!("Quote::BYTE_SIZE: {}", Quote::BYTE_SIZE);
+    info!("inp_qt.byte_len(): {}", inp_qt.byte_len());
+    assert_eq!(Quote::BYTE_SIZE, 8);
+    assert_eq!(inp_qt.byte_len(), 8);
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Default)]
+struct Typed<T>(
+    u16,
+    PhantomData<T>, // PhantomData is skipped automatically
+);
+
+#[derive(Debug, PartialEq, Default)]
+struct Marker;
+
+#[test]
+fn test_skip_phantom_data() {
+    skip_phantom_data()
+}
+fn skip_phantom_data() {
+    setup::log::configure();
+    let inp: Typed<Marker> = Typed(7, PhantomData);
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.len(), 2);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out: Typed<Marker> = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    assert_eq!(Typed::<Marker>::BYTE_SIZE, 2);
+    assert_eq!(inp.byte_len(), 2);
+}
+
+fn main() {
+    skip();
+    skip_size_len();
+    skip_phantom_data();
+}
@@ -0,0 +1,162 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(transparent)] // uses endian of the serializer & deserializer unless the parent struct sets it explicitly
+struct Qty(u32);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Side {
+    Buy,
+    Sell,
+}
+impl From<Side> for u8 {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Buy => b'B',
+            Side::Sell => b'S',
+        }
+    }
+}
+impl TryFrom<u8> for Side {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'B' => Ok(Side::Buy),
+            b'S' => Ok(Side::Sell),
+            _ => Err(value),
+        }
+    }
+}
+
+/// domain type stored on the wire as `u64` nanos, only values up to `u32::MAX` seconds are representable
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Nanos(u128);
+impl TryFrom<Nanos> for u64 {
+    type Error = std::num::TryFromIntError;
+    fn try_from(nanos: Nanos) -> Result<Self, Self::Error> {
+        u64::try_from(nanos.0)
+    }
+}
+impl From<u64> for Nanos {
+    fn from(value: u64) -> Self {
+        Nanos(value as u128)
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct OrderBe {
+    qty: Qty,
+    #[byteserde(as = "u8")]
+    side: Side,
+    #[byteserde(as = "u64")]
+    elapsed: Nanos,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "le")]
+struct OrderLe(Qty, #[byteserde(as = "u8")] Side, #[byteserde(as = "u64")] Nanos);
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Fill {
+    #[byteserde(count(2))]
+    qtys: Vec<Qty>, // applies to each element
+    #[byteserde(endian = "le")]
+    other: Vec<Qty>, // member level endian takes precedence
+}
+
+#[test]
+fn test_transparent() {
+    transparent()
+}
+fn transparent() {
+    setup::log::configure();
+    let inp = OrderBe { qty: Qty(1), side: Side::Sell, elapsed: Nanos(2) };
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x00, 0x00, 0x00, 0x01,                         // qty
+        b'S',                                           // side
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, // elapsed
+    ]);
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp).unwrap();
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out: OrderBe = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: OrderBe = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+    assert_eq!(OrderBe::BYTE_SIZE, 13);
+    assert_eq!(inp.byte_len(), 13);
+
+    // same newtype inside of a little endian parent
+    let inp = OrderLe(Qty(1), Side::Buy, Nanos(2));
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser_stack.as_slice(), &[
+        0x01, 0x00, 0x00, 0x00,                         // qty
+        b'B',                                           // side
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // elapsed
+    ]);
+    let out: OrderLe = from_serializer_stack(&ser_stack).unwrap();
+    assert_eq!(out, inp);
+
+    // inherited by each element, endian of the caller is ignored
+    let inp = Fill { qtys: vec![Qty(1), Qty(2)], other: vec![Qty(3)] };
+    let mut ser = ByteSerializerHeap::default();
+    ser.set_endian(Endian::Big);
+    inp.byte_serialize_heap(&mut ser).unwrap();
+    info!("ser: {ser:#x}");
+    #[rustfmt::skip]
+    assert_eq!(ser.as_slice(), &[
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, // qtys
+        0x03, 0x00, 0x00, 0x00,                         // other
+    ]);
+    assert_eq!(ser.endian(), Endian::Big); // restored
+    let mut des = ByteDeserializerSlice::new(ser.as_slice());
+    let out: Fill = des.set_endian(Endian::Big).deserialize().unwrap();
+    assert_eq!(out, inp);
+    assert_eq!(des.endian(), Endian::Big); // restored
+
+    // standalone newtype uses serializer's endian
+    let mut ser = ByteSerializerHeap::default();
+    ser.set_endian(Endian::Big);
+    Qty(1).byte_serialize_heap(&mut ser).unwrap();
+    assert_eq!(ser.as_slice(), &[0x00, 0x00, 0x00, 0x01]);
+}
+
+#[test]
+fn test_conversion_errors() {
+    conversion_errors()
+}
+fn conversion_errors() {
+    setup::log::configure();
+    let inp = OrderBe { qty: Qty(1), side: Side::Buy, elapsed: Nanos(u64::MAX as u128 + 1) };
+    let err = to_serializer_stack::<128, _>(&inp).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert!(err.message.contains("OrderBe.elapsed #[byteserde(as = \"u64\")] member can't be converted to u64"));
+
+    let bytes = [0x00, 0x00, 0x00, 0x01, b'X', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02];
+    let err = from_slice::<OrderBe>(&bytes).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedValue);
+    assert!(err.message.contains("OrderBe.side #[byteserde(as = \"u8\")] value 88 can't be converted to Side"));
+}
+
+fn main() {
+    transparent();
+    conversion_errors();
+}
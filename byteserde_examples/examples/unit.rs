@@ -18,7 +18,7 @@ fn test_bytes() {
 fn bytes() {
     setup::log::configure();
 
-    let inp_num = UnitNothing::default();
+    let inp_num = UnitNothing;
 
     // stack
     let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_num).unwrap();
@@ -41,7 +41,7 @@ fn test_bytes_size_len() {
 }
 fn bytes_size_len() {
     setup::log::configure();
-    let ln_of = UnitNothing::default().byte_len();
+    let ln_of = UnitNothing.byte_len();
     let sz_of = UnitNothing::byte_size();
     let sz_of_aligned = size_of::<UnitNothing>();
     info!("ln_of: {ln_of}");
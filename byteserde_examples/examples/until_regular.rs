@@ -0,0 +1,146 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use byteserde_types::prelude::*;
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+struct Point(u8, u8);
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Record {
+    #[byteserde(until = 0x00)] // null terminated C string
+    name: StringAscii,
+    #[byteserde(until = b"\r\n")] // multi byte terminator
+    line: String,
+    #[byteserde(until = 0xFF)] // 0xFF terminated list of bytes
+    bytes: Vec<u8>,
+    #[byteserde(until = [0xFF, 0xFF])] // 0xFFFF terminated list of numerics
+    numerics: Vec<u16>,
+    #[byteserde(until = b';')] // list of structs
+    points: Vec<Point>,
+}
+impl Default for Record {
+    fn default() -> Self {
+        Record {
+            name: b"hello".as_slice().into(),
+            line: "utf-8 ©".to_string(),
+            bytes: vec![1, 2, 3],
+            numerics: vec![1, 2],
+            points: vec![Point(1, 2), Point(3, 4)],
+        }
+    }
+}
+
+#[test]
+fn test_until() {
+    until()
+}
+fn until() {
+    setup::log::configure();
+    let inp_rec = Record::default();
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_rec).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    let mut expected = b"hello\x00".to_vec();
+    expected.extend_from_slice("utf-8 ©\r\n".as_bytes());
+    expected.extend_from_slice(&[1, 2, 3, 0xFF]);
+    expected.extend_from_slice(&[0, 1, 0, 2, 0xFF, 0xFF]);
+    expected.extend_from_slice(&[1, 2, 3, 4, b';']);
+    assert_eq!(ser_stack.as_slice(), expected);
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_rec).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_rec: Record = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_rec: {inp_rec:?}");
+    info!("out_rec: {out_rec:?}");
+    assert_eq!(out_rec, inp_rec);
+
+    // len includes terminators
+    assert_eq!(inp_rec.byte_len(), expected.len());
+
+    // terminator is only matched at element boundaries, here bytes 00 FF FF 01 span two elements
+    let inp_rec = Record { numerics: vec![0x00FF, 0xFF01], ..Default::default() };
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_rec).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    let out_rec: Record = from_serializer_heap(&ser_heap).unwrap();
+    assert_eq!(out_rec, inp_rec);
+}
+
+#[test]
+fn test_until_errors() {
+    until_errors()
+}
+fn until_errors() {
+    setup::log::configure();
+
+    // payload containing the terminator is rejected
+    let inp_rec = Record {
+        name: b"hel\x00lo".as_slice().into(),
+        ..Default::default()
+    };
+    let err = to_serializer_stack::<128, _>(&inp_rec).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Record.name #[byteserde(until = ..)] serialized payload contains terminator: [00]");
+    let err = to_serializer_heap(&inp_rec).unwrap_err();
+    assert_eq!(err.message, "Record.name #[byteserde(until = ..)] serialized payload contains terminator: [00]");
+
+    // missing terminator
+    let err = from_slice::<Record>(b"hello").unwrap_err();
+    info!("err: {err}");
+    assert!(err.message.starts_with("terminator: [00] not found"));
+
+    // element equal to the terminator is rejected
+    let inp_rec = Record { numerics: vec![1, 0xFFFF], ..Default::default() };
+    let err = to_serializer_heap(&inp_rec).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Record.numerics #[byteserde(until = ..)] serialized payload contains terminator: [ff, ff]");
+
+    // terminator must start at an element boundary
+    let err = from_slice::<Record>(b"a\x00b\r\n\xFF\x00\x01\x02\xFF\xFF").unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Record.numerics #[byteserde(until = ..)] terminator: [ff, ff] not found at an element boundary");
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq)]
+struct Line(
+    #[byteserde(until = b'\n', consume_terminator = false)] // terminator is left for the next member
+    Vec<u8>,
+    #[byteserde(expect(b'\n'))]
+    u8,
+);
+
+#[test]
+fn test_until_not_consumed() {
+    until_not_consumed()
+}
+fn until_not_consumed() {
+    setup::log::configure();
+    let inp = Line(b"abc".to_vec(), b'\n');
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), b"abc\n");
+    assert_eq!(inp.byte_len(), 4);
+
+    let out: Line = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+    let out: Line = from_bytes(ser_stack.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out, inp);
+}
+
+fn main() {
+    until();
+    until_errors();
+    until_not_consumed();
+}
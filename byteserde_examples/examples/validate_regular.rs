@@ -0,0 +1,169 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack};
+use byteserde_types::prelude::*;
+use log::info;
+use unittest::setup;
+
+mod rules {
+    use super::Order;
+    use byteserde_types::prelude::StringAscii;
+
+    pub fn positive(qty: &u32) -> Result<(), String> {
+        match *qty > 0 {
+            true => Ok(()),
+            false => Err(format!("qty: {qty} must be positive")),
+        }
+    }
+    pub fn price_band(price: &i64) -> Result<(), &'static str> {
+        match (1..=1_000_000).contains(price) {
+            true => Ok(()),
+            false => Err("price outside of 1..=1_000_000 band"),
+        }
+    }
+    pub fn max_len(text: &StringAscii) -> Result<(), String> {
+        match text.len() <= 8 {
+            true => Ok(()),
+            false => Err(format!("text len: {} exceeds 8", text.len())),
+        }
+    }
+    pub fn notional(order: &Order) -> Result<(), String> {
+        match order.qty as i64 * order.price <= 10_000_000 {
+            true => Ok(()),
+            false => Err(format!("notional: {} exceeds 10_000_000", order.qty as i64 * order.price)),
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be", validate = rules::notional)] // struct level, runs after all members are deserialized
+pub struct Order {
+    #[byteserde(validate = rules::positive, validate_ser = true)] // also runs before serialization
+    qty: u32,
+    #[byteserde(validate = rules::price_band)] // only runs during deserialization
+    price: i64,
+    #[byteserde(validate = rules::max_len)]
+    text: StringAscii,
+}
+impl Default for Order {
+    fn default() -> Self {
+        Order {
+            qty: 100,
+            price: 50,
+            text: b"hello".as_slice().into(),
+        }
+    }
+}
+
+#[test]
+fn test_validate() {
+    validate()
+}
+fn validate() {
+    setup::log::configure();
+    let inp_ord = Order::default();
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_ord).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_ord).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_ord: Order = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_ord: {inp_ord:?}");
+    info!("out_ord: {out_ord:?}");
+    assert_eq!(out_ord, inp_ord);
+}
+
+#[test]
+fn test_validate_failed() {
+    validate_failed()
+}
+fn validate_failed() {
+    setup::log::configure();
+
+    // validate_ser = true rejects zero qty before it is serialized
+    let zero_qty = Order { qty: 0, ..Default::default() };
+    let err = to_serializer_stack::<128, _>(&zero_qty).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::ValidationFailed);
+    assert_eq!(err.message, "Order.qty #[byteserde(validate = rules::positive)] failed: qty: 0 must be positive");
+    let err = to_serializer_heap(&zero_qty).unwrap_err();
+    assert_eq!(err.message, "Order.qty #[byteserde(validate = rules::positive)] failed: qty: 0 must be positive");
+
+    // price_band only runs on deserialization
+    let bad_price = Order { price: -1, ..Default::default() };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&bad_price).unwrap();
+    let err = from_serializer_stack::<128, Order>(&ser_stack).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Order.price #[byteserde(validate = rules::price_band)] failed: price outside of 1..=1_000_000 band");
+
+    let long_text = Order {
+        text: b"way too long".as_slice().into(),
+        ..Default::default()
+    };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&long_text).unwrap();
+    let err = from_serializer_stack::<128, Order>(&ser_stack).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Order.text #[byteserde(validate = rules::max_len)] failed: text len: 12 exceeds 8");
+
+    // struct level
+    let big_notional = Order {
+        qty: 1_000,
+        price: 1_000_000,
+        ..Default::default()
+    };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&big_notional).unwrap();
+    let err = from_serializer_stack::<128, Order>(&ser_stack).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.kind(), ErrorKind::ValidationFailed);
+    assert_eq!(err.message, "Order #[byteserde(validate = rules::notional)] failed: notional: 1000000000 exceeds 10_000_000");
+}
+
+fn non_zero(v: &u8) -> Result<(), &'static str> {
+    match *v {
+        0 => Err("must not be zero"),
+        _ => Ok(()),
+    }
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+#[byteserde(validate = non_zero_sum, validate_ser = true)]
+struct Pair(#[byteserde(validate = non_zero)] u8, u8);
+fn non_zero_sum(pair: &Pair) -> Result<(), &'static str> {
+    match pair.0 as u16 + pair.1 as u16 {
+        0 => Err("sum must not be zero"),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn test_validate_tuple() {
+    validate_tuple()
+}
+fn validate_tuple() {
+    setup::log::configure();
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&Pair(1, 0)).unwrap();
+    let out: Pair = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Pair(1, 0));
+
+    let err = from_slice::<Pair>(&[0, 1]).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Pair.0 #[byteserde(validate = non_zero)] failed: must not be zero");
+
+    let err = to_serializer_heap(&Pair(0, 0)).unwrap_err();
+    info!("err: {err}");
+    assert_eq!(err.message, "Pair #[byteserde(validate = non_zero_sum)] failed: sum must not be zero");
+}
+
+fn main() {
+    validate();
+    validate_failed();
+    validate_tuple();
+}
@@ -0,0 +1,109 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy, Default)]
+#[byteserde(endian = "be")]
+struct Extension {
+    a: u8,
+    b: u8,
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Default)]
+#[byteserde(endian = "be")]
+struct Order {
+    seq: u16,
+    #[byteserde(until_version = 1)] // dropped in version 2
+    legacy: u8,
+    #[byteserde(since = 2)] // added in version 2
+    price: u32,
+    #[byteserde(since = 2, until_version = 2)] // only present in version 2
+    flags: u8,
+    #[byteserde(since = 3)] // added in version 3
+    ext: Extension,
+}
+impl Order {
+    fn new() -> Self {
+        Order {
+            seq: 1,
+            legacy: 2,
+            price: 3,
+            flags: 4,
+            ext: Extension { a: 5, b: 6 },
+        }
+    }
+}
+
+#[test]
+fn test_version() {
+    version()
+}
+fn version() {
+    setup::log::configure();
+    let inp = Order::new();
+
+    for (version, expected, out) in [
+        (1, vec![0, 1, /* legacy */ 2], Order { seq: 1, legacy: 2, ..Default::default() }),
+        (2, vec![0, 1, /* price */ 0, 0, 0, 3, /* flags */ 4], Order { seq: 1, price: 3, flags: 4, ..Default::default() }),
+        (3, vec![0, 1, /* price */ 0, 0, 0, 3, /* ext */ 5, 6], Order { seq: 1, price: 3, ext: Extension { a: 5, b: 6 }, ..Default::default() }),
+    ] {
+        // stack
+        let mut ser_stack = ByteSerializerStack::<128>::default();
+        ser_stack.set_version(version).serialize(&inp).unwrap();
+        info!("version: {version}, ser_stack: {ser_stack:#x}");
+        assert_eq!(ser_stack.as_slice(), expected);
+
+        // heap
+        let mut ser_heap = ByteSerializerHeap::default();
+        ser_heap.set_version(version).serialize(&inp).unwrap();
+        info!("version: {version}, ser_heap: {ser_heap:#x}");
+        assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+        // deserialize, members outside of the version range are set to default
+        let mut des = ByteDeserializerSlice::new(ser_stack.as_slice());
+        let out_ord: Order = des.set_version(version).deserialize().unwrap();
+        info!("out_ord: {out_ord:?}");
+        assert_eq!(out_ord, out);
+        assert!(des.is_empty());
+
+        let mut des = ByteDeserializerBytes::new(ser_heap.as_slice().to_vec().into());
+        let out_ord: Order = des.set_version(version).deserialize().unwrap();
+        assert_eq!(out_ord, out);
+    }
+
+    // version not set, all members are present
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[0, 1, 2, 0, 0, 0, 3, 4, 5, 6]);
+    assert_eq!(inp.byte_len(), ser_stack.len());
+    let out_ord: Order = from_serializer_stack(&ser_stack).unwrap();
+    assert_eq!(out_ord, inp);
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Pair(u8, #[byteserde(since = 2)] u8);
+
+#[test]
+fn test_version_tuple() {
+    version_tuple()
+}
+fn version_tuple() {
+    setup::log::configure();
+    let mut des = ByteDeserializerSlice::new(&[1]);
+    let out: Pair = des.set_version(1).deserialize().unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Pair(1, 0));
+
+    let mut des = ByteDeserializerSlice::new(&[1, 2]);
+    let out: Pair = des.set_version(2).deserialize().unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Pair(1, 2));
+}
+
+fn main() {
+    version();
+    version_tuple();
+}
@@ -0,0 +1,103 @@
+mod unittest;
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf};
+use log::info;
+use unittest::setup;
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone, Copy)]
+#[byteserde(endian = "be")]
+struct Extension {
+    a: u16,
+    b: u16,
+}
+
+const HAS_PRICE: u8 = 0x01;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedLenOf, Debug, PartialEq, Clone)]
+#[byteserde(endian = "be")]
+struct Order {
+    version: u8,
+    flags: u8,
+    #[byteserde(when = flags & HAS_PRICE != 0)] // flag bit
+    price: Option<u64>,
+    #[byteserde(when = version >= 2)] // version number
+    ext: Option<Extension>,
+    qty: u32, // members may follow conditional ones
+}
+
+#[test]
+fn test_when() {
+    when()
+}
+fn when() {
+    setup::log::configure();
+
+    // all present
+    let inp_ord = Order {
+        version: 2,
+        flags: HAS_PRICE,
+        price: Some(100),
+        ext: Some(Extension { a: 1, b: 2 }),
+        qty: 7,
+    };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_ord).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.len(), 1 + 1 + 8 + 4 + 4);
+    assert_eq!(inp_ord.byte_len(), ser_stack.len());
+
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_ord).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    let out_ord: Order = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_ord: {inp_ord:?}");
+    info!("out_ord: {out_ord:?}");
+    assert_eq!(out_ord, inp_ord);
+    let out_ord: Order = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out_ord, inp_ord);
+
+    // none present
+    let inp_ord = Order {
+        version: 1,
+        flags: 0,
+        price: None,
+        ext: None,
+        qty: 7,
+    };
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_ord).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[1, 0, 0, 0, 0, 7]);
+    assert_eq!(inp_ord.byte_len(), ser_stack.len());
+
+    let out_ord: Order = from_serializer_stack(&ser_stack).unwrap();
+    info!("out_ord: {out_ord:?}");
+    assert_eq!(out_ord, inp_ord);
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Tagged(u8, #[byteserde(when = _0 == b'Y')] Option<u8>, u8);
+
+#[test]
+fn test_when_tuple() {
+    when_tuple()
+}
+fn when_tuple() {
+    setup::log::configure();
+    let out: Tagged = from_slice(&[b'Y', 1, 2]).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Tagged(b'Y', Some(1), 2));
+
+    let out: Tagged = from_slice(&[b'N', 2]).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, Tagged(b'N', None, 2));
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&Tagged(b'Y', Some(1), 2)).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice(), &[b'Y', 1, 2]);
+}
+
+fn main() {
+    when();
+    when_tuple();
+}
@@ -0,0 +1,137 @@
+mod unittest;
+use std::time::Duration;
+
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeBytes, ByteDeserializeSlice, ByteSerializeHeap, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use log::info;
+use unittest::setup;
+
+/// `Duration` encoded as `u32` seconds followed by `u32` nanos, big endian
+mod secs_nanos {
+    use byteserde::prelude::*;
+    use std::time::Duration;
+
+    pub fn serialize<S: ByteSerializer>(v: &Duration, ser: &mut S) -> byteserde::error::Result<()> {
+        ser.serialize_be(v.as_secs() as u32)?.serialize_be(v.subsec_nanos())?;
+        Ok(())
+    }
+    pub fn deserialize<D: ByteDeserializer>(des: &mut D) -> byteserde::error::Result<Duration> {
+        let secs: u32 = des.deserialize_be()?;
+        let nanos: u32 = des.deserialize_be()?;
+        Ok(Duration::new(secs as u64, nanos))
+    }
+    pub const fn byte_size() -> usize {
+        8
+    }
+    pub fn byte_len(_: &Duration) -> usize {
+        byte_size()
+    }
+}
+
+/// `f64` price encoded as `i64` with 4 implied decimal places, little endian
+mod price4 {
+    use byteserde::prelude::*;
+    const SCALE: f64 = 10_000.0;
+
+    pub fn serialize<S: ByteSerializer>(v: &f64, ser: &mut S) -> byteserde::error::Result<()> {
+        ser.serialize_le((v * SCALE).round() as i64)?;
+        Ok(())
+    }
+    pub fn deserialize<D: ByteDeserializer>(des: &mut D) -> byteserde::error::Result<f64> {
+        let v: i64 = des.deserialize_le()?;
+        Ok(v as f64 / SCALE)
+    }
+    pub const fn byte_size() -> usize {
+        8
+    }
+    pub fn byte_len(_: &f64) -> usize {
+        byte_size()
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, ByteDeserializeBytes, ByteSerializedSizeOf, ByteSerializedLenOf, Debug, PartialEq)]
+struct Trade {
+    id: u16,
+    #[byteserde(with = secs_nanos)]
+    time: Duration,
+    #[byteserde(with = price4)]
+    price: f64,
+}
+
+#[test]
+fn test_with() {
+    with()
+}
+fn with() {
+    setup::log::configure();
+    let inp_trd = Trade {
+        id: 1,
+        time: Duration::new(2, 3),
+        price: 12.3456,
+    };
+
+    // stack
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp_trd).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.as_slice()[2..6], 2_u32.to_be_bytes());
+    assert_eq!(ser_stack.as_slice()[6..10], 3_u32.to_be_bytes());
+    assert_eq!(ser_stack.as_slice()[10..18], 123_456_i64.to_le_bytes());
+
+    // heap
+    let ser_heap: ByteSerializerHeap = to_serializer_heap(&inp_trd).unwrap();
+    info!("ser_heap: {ser_heap:#x}");
+    assert_eq!(ser_stack.as_slice(), ser_heap.as_slice());
+
+    // deserialize
+    let out_trd: Trade = from_serializer_stack(&ser_stack).unwrap();
+    info!("inp_trd: {inp_trd:?}");
+    info!("out_trd: {out_trd:?}");
+    assert_eq!(out_trd, inp_trd);
+
+    // same codec works with ByteDeserializerBytes
+    let out_trd: Trade = from_bytes(ser_heap.as_slice().to_vec().into()).unwrap();
+    assert_eq!(out_trd, inp_trd);
+}
+
+#[test]
+fn test_with_size_len() {
+    with_size_len()
+}
+fn with_size_len() {
+    setup::log::configure();
+    let inp_trd = Trade {
+        id: 1,
+        time: Duration::new(2, 3),
+        price: 12.3456,
+    };
+    info!("Trade::BYTE_SIZE: {}", Trade::BYTE_SIZE);
+    assert_eq!(Trade::BYTE_SIZE, 18);
+    assert_eq!(inp_trd.byte_len(), 18);
+}
+
+#[derive(ByteSerializeStack, ByteSerializeHeap, ByteDeserializeSlice, Debug, PartialEq)]
+struct Elapsed(u8, #[byteserde(with = secs_nanos)] Duration);
+
+#[test]
+fn test_with_tuple() {
+    with_tuple()
+}
+fn with_tuple() {
+    setup::log::configure();
+    let inp = Elapsed(1, Duration::from_millis(1_500));
+
+    let ser_stack: ByteSerializerStack<128> = to_serializer_stack(&inp).unwrap();
+    info!("ser_stack: {ser_stack:#x}");
+    assert_eq!(ser_stack.len(), 9);
+
+    let out: Elapsed = from_serializer_stack(&ser_stack).unwrap();
+    info!("out: {out:?}");
+    assert_eq!(out, inp);
+}
+
+fn main() {
+    with();
+    with_size_len();
+    with_tuple();
+}
@@ -280,7 +280,7 @@ macro_rules! _common_char_ascii {
 /// Note that provided implementation already includes several traits which `SHOULD NOT` be included in the derive list.
 /// * [std::fmt::Debug] & [std::fmt::Display] - provides a human readable sting view of the `u8` byte as utf-8 char
 /// * [byteserde::prelude::ByteDeserializeSlice]- provides an implementation for deserializing from a byte stream, which returns [byteserde::prelude::SerDesError] if value on the
-/// stream does `not` match the `CONST` value.
+///   stream does `not` match the `CONST` value.
 ///
 /// # Examples
 /// ```
@@ -376,9 +376,7 @@ macro_rules! _common_const_char_ascii {
                     false => {
                         let ty = $NAME::default();
 
-                        Err(::byteserde::prelude::SerDesError {
-                            message: format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, $CONST, _0),
-                        })
+                        Err(::byteserde::prelude::SerDesError::new(format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, $CONST, _0)))
                     }
                 }
             }
@@ -392,9 +390,7 @@ macro_rules! _common_const_char_ascii {
                     false => {
                         let ty = $NAME::default();
 
-                        Err(::byteserde::prelude::SerDesError {
-                            message: format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, $CONST, _0),
-                        })
+                        Err(::byteserde::prelude::SerDesError::new(format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, $CONST, _0)))
                     }
                 }
             }
@@ -498,7 +494,7 @@ macro_rules! const_numeric {
 /// * `CONST` - `u16` byte value to be used as the value behind this struct
 /// * `ENDIAN` - endianness of the numeric type, must be either `le`, `be`, or `ne`, this will be passed directly to the `byteserde` attribute as #[byteserde(endian = "xx" )]
 /// * `derive(...)` -- `must include one of` the following `ByteSerializeStack`, `ByteSerializeHeap`, or `ByteDeserializeSlice` other wise the `#[byteserde(endian = $ENDIAN)]` attribute will fail to compile.
-/// Plus list of additional valid rust derive traits
+///   Plus list of additional valid rust derive traits
 ///
 /// # Derives
 /// Note that provided implementation already includes several traits which `SHOULD NOT` be included in the derive list.
@@ -686,7 +682,7 @@ macro_rules! i8_tuple {
 /// * `NAME` - name of the struct to be generated
 /// * `ENDIAN` - endianness of the numeric type, must be either `le`, `be`, or `ne`, this will be passed directly to the `byteserde` attribute as #[byteserde(endian = "xx" )]
 /// * `#[derive(...)]` -- `must include one of` the following `ByteSerializeStack`, `ByteSerializeHeap`, or `ByteDeserializeSlice` other wise the `#[byteserde(endian = $ENDIAN)]` attribute will fail to compile.
-/// Plus list of additional valid rust derive traits
+///   Plus list of additional valid rust derive traits
 ///
 /// # Derives
 /// Note that provided implementation already includes several traits which `SHOULD NOT` be included in the derive list.
@@ -458,9 +458,7 @@ impl<const CHAR: u8> ByteDeserializeSlice<ConstCharAscii<CHAR>> for ConstCharAsc
             false => {
                 let ty: ConstCharAscii<CHAR> = Default::default();
 
-                Err(SerDesError {
-                    message: format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, CHAR, _0),
-                })
+                Err(SerDesError::new(format!("Type {:?} expected: 0x{:02x} actual: 0x{:02x}", ty, CHAR, _0)))
             }
         }
     }